@@ -0,0 +1,39 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use generic_array::{typenum::*, ArrayLength, GenericArray};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut from_iter = c.benchmark_group("from_iter");
+
+    macro_rules! all_from_iter_benches {
+        ($($len:ty,)*) => {
+            $(bench_from_iter::<$len>(&mut from_iter);)*
+        }
+    }
+
+    all_from_iter_benches!(U4, U16, U64, U256, U1024, U4096,);
+
+    from_iter.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
+
+fn bench_from_iter<N: ArrayLength>(g: &mut BenchmarkGroup<'_, WallTime>) {
+    let fixture: Vec<u32> = (0..N::USIZE as u32).collect();
+
+    g.bench_function(format!("checked/N{:08}", N::USIZE), |b| {
+        b.iter(|| {
+            let iter = black_box(&fixture).iter().map(|&x| x ^ 0x5A5A);
+            black_box(iter.collect::<GenericArray<u32, N>>())
+        })
+    });
+
+    g.bench_function(format!("trusted/N{:08}", N::USIZE), |b| {
+        b.iter(|| {
+            let iter = black_box(&fixture).iter().map(|&x| x ^ 0x5A5A);
+            black_box(GenericArray::<u32, N>::from_trusted_len_iter(iter))
+        })
+    });
+}
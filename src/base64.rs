@@ -0,0 +1,160 @@
+//! Fixed-length base64 encoding and decoding of byte arrays, without allocation.
+//!
+//! The encoded length is computed at the type level as [`Base64Len<N>`](crate::Base64Len),
+//! so keys and digests can be encoded into stack buffers, which is useful in `no_std`
+//! environments without a heap. The standard alphabet from RFC 4648 is used, with padding.
+//!
+//! Example:
+//!
+//! ```rust
+//! use generic_array::{arr, GenericArray, typenum::U5};
+//!
+//! let encoded = arr![b'h', b'e', b'l', b'l', b'o'].to_base64();
+//! assert_eq!(encoded.as_slice(), b"aGVsbG8=");
+//!
+//! let decoded = GenericArray::<u8, U5>::from_base64(&encoded);
+//! assert_eq!(decoded.as_ref().map(|a| a.as_slice()), Ok(&b"hello"[..]));
+//! ```
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul},
+};
+
+use typenum::{Prod, Quot, Sum, U2, U3, U4};
+
+use crate::{ArrayLength, GenericArray};
+
+/// Length of the padded base64 encoding of `N` bytes, `4 * ceil(N / 3)`.
+pub type Base64Len<N> = Prod<Quot<Sum<N, U2>, U3>, U4>;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Error for [`from_base64`](GenericArray::from_base64)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The character at byte offset `index` is not in the base64 alphabet,
+    /// or it is the last character before the padding and has non-zero unused bits.
+    InvalidChar {
+        /// Byte offset of the invalid character in the input
+        index: usize,
+    },
+    /// The character at byte offset `index` should be `=` padding for the array length.
+    InvalidPadding {
+        /// Byte offset of the invalid padding in the input
+        index: usize,
+    },
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base64Error::InvalidChar { index } => {
+                write!(f, "Base64Error: Invalid character at index {}", index)
+            }
+            Base64Error::InvalidPadding { index } => {
+                write!(f, "Base64Error: Expected padding at index {}", index)
+            }
+        }
+    }
+}
+
+#[inline]
+const fn base64_digit(c: u8) -> Option<u32> {
+    Some(match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    } as u32)
+}
+
+impl<N: ArrayLength> GenericArray<u8, N>
+where
+    N: Add<U2>,
+    Sum<N, U2>: Div<U3>,
+    Quot<Sum<N, U2>, U3>: Mul<U4>,
+    Base64Len<N>: ArrayLength,
+{
+    /// Encodes the array as padded base64 into a fixed-length array of `4 * ceil(N / 3)` ASCII bytes.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![0xFBu8, 0xFF].to_base64().as_slice(), b"+/8=");
+    /// assert_eq!(arr![1u8, 2, 3].to_base64().as_slice(), b"AQID");
+    /// ```
+    pub fn to_base64(&self) -> GenericArray<u8, Base64Len<N>> {
+        let mut out = GenericArray::<u8, Base64Len<N>>::default();
+
+        for (src, dst) in self.chunks(3).zip(out.chunks_exact_mut(4)) {
+            let mut bytes = [0; 4];
+            bytes[1..=src.len()].copy_from_slice(src);
+            let n = u32::from_be_bytes(bytes);
+
+            for (i, c) in dst.iter_mut().enumerate() {
+                *c = match i <= src.len() {
+                    true => ALPHABET[(n >> (18 - 6 * i)) as usize & 63],
+                    false => b'=',
+                };
+            }
+        }
+
+        out
+    }
+
+    /// Decodes a byte array from its padded base64 encoding of exactly `4 * ceil(N / 3)` characters.
+    ///
+    /// Only the standard alphabet is accepted, padding must match the array length,
+    /// and the unused bits of the last character before the padding must be zero,
+    /// so each array has exactly one valid encoding.
+    ///
+    /// ```
+    /// # use generic_array::{arr, Base64Error, GenericArray, typenum::U2};
+    /// type A = GenericArray<u8, U2>;
+    ///
+    /// assert_eq!(A::from_base64(GenericArray::from_slice(b"+/8=")), Ok(arr![0xFB, 0xFF]));
+    /// assert_eq!(
+    ///     A::from_base64(GenericArray::from_slice(b"+/8A")),
+    ///     Err(Base64Error::InvalidPadding { index: 3 })
+    /// );
+    /// assert_eq!(
+    ///     A::from_base64(GenericArray::from_slice(b"+/9=")),
+    ///     Err(Base64Error::InvalidChar { index: 2 })
+    /// );
+    /// ```
+    pub fn from_base64(encoded: &GenericArray<u8, Base64Len<N>>) -> Result<Self, Base64Error> {
+        let mut array = GenericArray::<u8, N>::default();
+
+        for (chunk, (src, dst)) in encoded.chunks_exact(4).zip(array.chunks_mut(3)).enumerate() {
+            let offset = chunk * 4;
+            let mut n = 0;
+
+            for (i, &c) in src.iter().enumerate() {
+                let index = offset + i;
+
+                if i > dst.len() {
+                    if c != b'=' {
+                        return Err(Base64Error::InvalidPadding { index });
+                    }
+                    continue;
+                }
+
+                n |= base64_digit(c).ok_or(Base64Error::InvalidChar { index })? << (18 - 6 * i);
+            }
+
+            let bytes = n.to_be_bytes();
+
+            if bytes[dst.len() + 1..].iter().any(|&b| b != 0) {
+                return Err(Base64Error::InvalidChar {
+                    index: offset + dst.len(),
+                });
+            }
+
+            dst.copy_from_slice(&bytes[1..=dst.len()]);
+        }
+
+        Ok(array)
+    }
+}
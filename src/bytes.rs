@@ -0,0 +1,410 @@
+//! Operations specific to arrays of bytes
+
+use core::ops::{Add, Mul, Sub};
+use core::str::{self, Utf8Error};
+
+use typenum::operator_aliases::{Add1, Diff, Prod};
+use typenum::{NonZero, B1, U8};
+
+use crate::sequence::GenericSequence;
+use crate::{ArrayLength, GenericArray, LengthError};
+
+impl<N: ArrayLength> GenericArray<u8, N> {
+    /// XORs each byte of `other` into the corresponding byte of `self`, in place.
+    ///
+    /// The control flow and memory accesses do not depend on the contents of either array,
+    /// but no further guarantees are made about constant-time execution.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut data = arr![0x00u8, 0x0F, 0xF0, 0xFF];
+    ///
+    /// data.xor_assign(&arr![0xFF, 0xFF, 0x0F, 0x0F]);
+    ///
+    /// assert_eq!(data, arr![0xFF, 0xF0, 0xFF, 0xF0]);
+    /// ```
+    #[inline]
+    pub fn xor_assign(&mut self, other: &GenericArray<u8, N>) {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            *a ^= *b;
+        }
+    }
+
+    /// Consumes the array, returning the element-wise XOR of it and `other`.
+    ///
+    /// See [`xor_assign`](GenericArray::xor_assign) for details.
+    #[inline]
+    pub fn xor(mut self, other: Self) -> Self {
+        self.xor_assign(&other);
+        self
+    }
+
+    /// Interprets the entire array as a UTF-8 string.
+    ///
+    /// Every byte is validated, including any trailing NUL bytes, which are kept in the output.
+    /// See [`as_str_trimmed`](GenericArray::as_str_trimmed) for NUL-terminated strings.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![b'h', b'i'].as_str(), Ok("hi"));
+    /// assert!(arr![0xFF, b'i'].as_str().is_err());
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        str::from_utf8(self.as_slice())
+    }
+
+    /// Interprets the array as a UTF-8 string ending at the first NUL byte,
+    /// as is common for fixed-size C-style string fields.
+    ///
+    /// If there is no NUL byte, the entire array is used. Bytes after the first NUL byte
+    /// are not validated.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let name = arr![b'a', b'b', 0, 0, 0xFF];
+    ///
+    /// assert_eq!(name.as_str_trimmed(), Ok("ab"));
+    /// assert!(name.as_str().is_err());
+    /// ```
+    #[inline]
+    pub fn as_str_trimmed(&self) -> Result<&str, Utf8Error> {
+        let len = self.iter().position(|&b| b == 0).unwrap_or(N::USIZE);
+
+        str::from_utf8(&self[..len])
+    }
+
+    /// Creates an array from the bytes of `s`, right-padded with `pad` to the length of the array.
+    ///
+    /// This is the inverse of reading a fixed-width text field, such as with
+    /// [`as_str_trimmed`](GenericArray::as_str_trimmed) when padding with NUL bytes.
+    ///
+    /// Returns [`LengthError`] if `s` is longer than the array.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U5};
+    /// let field = GenericArray::<u8, U5>::from_str_padded("ab", b' ').unwrap();
+    /// assert_eq!(field, arr![b'a', b'b', b' ', b' ', b' ']);
+    ///
+    /// assert!(GenericArray::<u8, U5>::from_str_padded("abcdef", b' ').is_err());
+    /// ```
+    #[inline]
+    pub fn from_str_padded(s: &str, pad: u8) -> Result<Self, LengthError> {
+        let bytes = s.as_bytes();
+
+        if bytes.len() > N::USIZE {
+            return Err(LengthError);
+        }
+
+        Ok(GenericArray::generate(|i| match bytes.get(i) {
+            Some(&b) => b,
+            None => pad,
+        }))
+    }
+
+    /// Converts each ASCII uppercase letter to lowercase in place, leaving other bytes unchanged.
+    ///
+    /// See [`slice::make_ascii_lowercase`].
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_mut_slice().make_ascii_lowercase();
+    }
+
+    /// Converts each ASCII lowercase letter to uppercase in place, leaving other bytes unchanged.
+    ///
+    /// See [`slice::make_ascii_uppercase`].
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_mut_slice().make_ascii_uppercase();
+    }
+
+    /// Consumes the array and returns it with each ASCII uppercase letter converted to lowercase,
+    /// leaving other bytes unchanged.
+    ///
+    /// This is the non-allocating equivalent of `slice::to_ascii_lowercase`,
+    /// which remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let id = arr![b'A', b'b', b'-', b'Z', 0xC9];
+    ///
+    /// assert_eq!(id.into_ascii_lowercase(), arr![b'a', b'b', b'-', b'z', 0xC9]);
+    /// ```
+    #[inline]
+    pub fn into_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+
+    /// Consumes the array and returns it with each ASCII lowercase letter converted to uppercase,
+    /// leaving other bytes unchanged.
+    ///
+    /// This is the non-allocating equivalent of `slice::to_ascii_uppercase`,
+    /// which remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let id = arr![b'A', b'b', b'-', b'z', 0xE9];
+    ///
+    /// assert_eq!(id.into_ascii_uppercase(), arr![b'A', b'B', b'-', b'Z', 0xE9]);
+    /// ```
+    #[inline]
+    pub fn into_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+
+    /// Counts the number of bytes equal to `needle`.
+    ///
+    /// This is functionally identical to `self.iter().filter(|&&b| b == needle).count()`,
+    /// but is written as a branchless sum so that it can be vectorized. Matches are counted
+    /// in `u8` lanes over chunks of up to 255 bytes, which cannot overflow, so that each
+    /// vector compare covers a full register of bytes before the lanes are summed.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let line = arr![b'a', b',', b'b', b',', b'c'];
+    ///
+    /// assert_eq!(line.count_byte(b','), 2);
+    /// ```
+    #[inline]
+    pub fn count_byte(&self, needle: u8) -> usize {
+        self.chunks(255)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0u8, |count, &b| count + (b == needle) as u8) as usize
+            })
+            .sum()
+    }
+
+    /// Rotates the bits of the array left by `n`, treating it as a single `8 * N`-bit
+    /// big-endian integer, where the first byte is the most significant.
+    ///
+    /// Bits shifted out of the most significant end wrap around to the least significant end,
+    /// carrying across byte boundaries. `n` may be larger than `8 * N`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![0b1000_0001u8, 0b0000_0011];
+    ///
+    /// a.rotate_bits_left(4);
+    ///
+    /// assert_eq!(a, arr![0b0001_0000, 0b0011_1000]);
+    /// ```
+    pub fn rotate_bits_left(&mut self, n: usize) {
+        let bits = N::USIZE * 8;
+
+        if bits == 0 {
+            return;
+        }
+
+        let n = n % bits;
+        let (byte_shift, bit_shift) = (n / 8, (n % 8) as u32);
+
+        self.rotate_left(byte_shift);
+
+        if bit_shift != 0 {
+            let first = self[0];
+
+            for i in 0..N::USIZE {
+                let next = match self.get(i + 1) {
+                    Some(&next) => next,
+                    None => first,
+                };
+
+                self[i] = (self[i] << bit_shift) | (next >> (8 - bit_shift));
+            }
+        }
+    }
+
+    /// Rotates the bits of the array right by `n`, treating it as a single `8 * N`-bit
+    /// big-endian integer, where the first byte is the most significant.
+    ///
+    /// See [`rotate_bits_left`](GenericArray::rotate_bits_left) for details.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![0b1000_0001u8, 0b0000_0011];
+    ///
+    /// a.rotate_bits_right(1);
+    ///
+    /// assert_eq!(a, arr![0b1100_0000, 0b1000_0001]);
+    /// ```
+    pub fn rotate_bits_right(&mut self, n: usize) {
+        let bits = N::USIZE * 8;
+
+        if bits == 0 {
+            return;
+        }
+
+        self.rotate_bits_left(bits - n % bits);
+    }
+
+    /// Returns the number of bits that differ between `self` and `other`.
+    ///
+    /// This counts differing bits, not differing bytes, so two arrays differing
+    /// in a single byte may have a distance of up to 8.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![0b1111_0000u8, 0xAA];
+    /// let b = arr![0b1111_1111u8, 0xAA];
+    ///
+    /// assert_eq!(a.hamming_distance(&b), 4);
+    /// assert_eq!(a.hamming_distance(&a), 0);
+    /// ```
+    #[inline]
+    pub fn hamming_distance(&self, other: &GenericArray<u8, N>) -> u32 {
+        let (lhs, rhs) = (self.chunks_exact(8), other.chunks_exact(8));
+        let (lhs_rem, rhs_rem) = (lhs.remainder(), rhs.remainder());
+
+        let word = |chunk: &[u8]| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(chunk);
+            u64::from_ne_bytes(bytes)
+        };
+
+        let words: u32 = lhs
+            .zip(rhs)
+            .map(|(a, b)| (word(a) ^ word(b)).count_ones())
+            .sum();
+
+        let bytes: u32 = lhs_rem
+            .iter()
+            .zip(rhs_rem)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+
+        words + bytes
+    }
+
+    /// Computes a polynomial rolling hash of each contiguous window of `W` bytes, in order,
+    /// as used by the Rabin-Karp substring search.
+    ///
+    /// The hash of a window `s` is `(s[0] * base^(W-1) + s[1] * base^(W-2) + ... + s[W-1]) % modulus`.
+    /// There are `N - W + 1` windows, with `W <= N` checked at compile-time. Each hash is
+    /// derived from the previous one by removing the leading byte and appending the trailing one,
+    /// so only `O(N)` operations are performed in total. Intermediate products are computed
+    /// with 128-bit integers, so any `base` and `modulus` are supported without overflow.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U3};
+    /// let text = arr![b'a', b'b', b'c', b'a', b'b', b'c'];
+    /// let hashes = text.rolling_hashes::<U3>(256, 1_000_000_007);
+    ///
+    /// // equal windows have equal hashes
+    /// assert_eq!(hashes[0], hashes[3]);
+    /// assert_ne!(hashes[0], hashes[1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero, or if `W` is zero, which is only possible with a non-canonical
+    /// length such as `UInt<UTerm, B0>` that `typenum` still considers [`NonZero`].
+    #[track_caller]
+    #[inline]
+    pub fn rolling_hashes<W>(&self, base: u64, modulus: u64) -> GenericArray<u64, Add1<Diff<N, W>>>
+    where
+        W: ArrayLength + NonZero,
+        N: Sub<W>,
+        Diff<N, W>: Add<B1>,
+        Add1<Diff<N, W>>: ArrayLength,
+    {
+        assert!(modulus != 0, "modulus must be non-zero");
+        assert!(W::USIZE != 0, "window must be non-empty");
+
+        let m = modulus as u128;
+        let base = base as u128 % m;
+
+        // base^(W-1) % modulus, the weight of the leading byte of a window
+        let lead = (1..W::USIZE).fold(1 % m, |acc, _| acc * base % m);
+
+        let mut hash = self[..W::USIZE]
+            .iter()
+            .fold(0, |acc, &b| (acc * base + b as u128) % m);
+
+        GenericArray::generate(|i| {
+            if i > 0 {
+                let removed = self[i - 1] as u128 * lead % m;
+                hash = ((hash + m - removed) % m * base + self[i + W::USIZE - 1] as u128) % m;
+            }
+
+            hash as u64
+        })
+    }
+}
+
+impl<N: ArrayLength> GenericArray<u8, N>
+where
+    N: Mul<U8>,
+    Prod<N, U8>: ArrayLength,
+{
+    /// Packs each group of 8 bools into a byte, most significant bit first.
+    ///
+    /// Bit `i` of the input becomes bit `7 - i % 8` of byte `i / 8`, where bit 0 is the
+    /// least significant. See [`from_bits_lsb`](GenericArray::from_bits_lsb) for the opposite order.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U1};
+    /// let bits = arr![true, false, false, false, false, false, true, true];
+    ///
+    /// assert_eq!(GenericArray::<u8, U1>::from_bits(&bits), arr![0b1000_0011]);
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: &GenericArray<bool, Prod<N, U8>>) -> Self {
+        let bytes = GenericArray::<bool, U8>::chunks_from_slice(bits).0;
+
+        GenericArray::generate(|i| {
+            bytes[i]
+                .iter()
+                .fold(0, |byte, &bit| (byte << 1) | bit as u8)
+        })
+    }
+
+    /// Unpacks each byte into 8 bools, most significant bit first.
+    ///
+    /// This is the inverse of [`from_bits`](GenericArray::from_bits).
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let bits = arr![0b1000_0011u8].to_bits();
+    ///
+    /// assert_eq!(bits, arr![true, false, false, false, false, false, true, true]);
+    /// ```
+    #[inline]
+    pub fn to_bits(&self) -> GenericArray<bool, Prod<N, U8>> {
+        GenericArray::generate(|i| (self[i / 8] >> (7 - i % 8)) & 1 == 1)
+    }
+
+    /// Packs each group of 8 bools into a byte, least significant bit first.
+    ///
+    /// Bit `i` of the input becomes bit `i % 8` of byte `i / 8`, where bit 0 is the
+    /// least significant. See [`from_bits`](GenericArray::from_bits) for the opposite order.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U1};
+    /// let bits = arr![true, false, false, false, false, false, true, true];
+    ///
+    /// assert_eq!(GenericArray::<u8, U1>::from_bits_lsb(&bits), arr![0b1100_0001]);
+    /// ```
+    #[inline]
+    pub fn from_bits_lsb(bits: &GenericArray<bool, Prod<N, U8>>) -> Self {
+        let bytes = GenericArray::<bool, U8>::chunks_from_slice(bits).0;
+
+        GenericArray::generate(|i| {
+            bytes[i]
+                .iter()
+                .rev()
+                .fold(0, |byte, &bit| (byte << 1) | bit as u8)
+        })
+    }
+
+    /// Unpacks each byte into 8 bools, least significant bit first.
+    ///
+    /// This is the inverse of [`from_bits_lsb`](GenericArray::from_bits_lsb).
+    #[inline]
+    pub fn to_bits_lsb(&self) -> GenericArray<bool, Prod<N, U8>> {
+        GenericArray::generate(|i| (self[i / 8] >> (i % 8)) & 1 == 1)
+    }
+}
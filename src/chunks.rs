@@ -0,0 +1,705 @@
+//! Splitting a `GenericArray` into chunks and sub-arrays
+
+use core::iter::{FusedIterator, Rev};
+use core::mem::ManuallyDrop;
+use core::ops::{Add, Div, Sub};
+use core::ptr;
+use core::slice;
+
+use typenum::operator_aliases::{Diff, PartialQuot, Quot};
+use typenum::{IsGreaterOrEqual, PartialDiv, True, U2};
+
+use crate::sequence::Split;
+use crate::{const_transmute, ArrayLength, GenericArray};
+
+/// An iterator over non-overlapping mutable `C`-sized chunks of a [`GenericArray`],
+/// yielding `&mut GenericArray<T, C>`.
+///
+/// Created by [`GenericArray::chunks_mut_typed`].
+#[derive(Debug)]
+pub struct ChunksMut<'a, T, C: ArrayLength> {
+    inner: slice::IterMut<'a, GenericArray<T, C>>,
+}
+
+impl<'a, T, C: ArrayLength> ChunksMut<'a, T, C> {
+    /// Returns the chunks that have not yet been yielded, consuming the iterator.
+    #[inline]
+    pub fn into_slice(self) -> &'a mut [GenericArray<T, C>] {
+        self.inner.into_slice()
+    }
+}
+
+impl<'a, T, C: ArrayLength> Iterator for ChunksMut<'a, T, C> {
+    type Item = &'a mut GenericArray<T, C>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T, C: ArrayLength> DoubleEndedIterator for ChunksMut<'_, T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T, C: ArrayLength> ExactSizeIterator for ChunksMut<'_, T, C> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T, C: ArrayLength> FusedIterator for ChunksMut<'_, T, C> {}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Splits a mutable array reference into two non-overlapping mutable array references,
+    /// the first containing the first `M` elements and the second containing the rest.
+    ///
+    /// This is the typed equivalent of [`split_at_mut`](slice::split_at_mut), with `M <= N` checked at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (head, tail) = a.split_at_mut_typed::<U2>();
+    /// head[0] = 10;
+    /// tail[0] = 30;
+    ///
+    /// assert_eq!(a, arr![10, 2, 30, 4, 5]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    pub fn split_at_mut_typed<M>(
+        &mut self,
+    ) -> (&mut GenericArray<T, M>, &mut GenericArray<T, Diff<N, M>>)
+    where
+        M: ArrayLength,
+        N: Sub<M>,
+        Diff<N, M>: ArrayLength,
+    {
+        Split::<T, M>::split(self)
+    }
+
+    /// Consumes the array, splitting it into two arrays of explicitly given lengths `A` and `B`,
+    /// where `A + B` must equal `N`.
+    ///
+    /// Unlike [`Split::split`], which derives the second length from the first,
+    /// both lengths are named at the call site, and a mismatch is rejected at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::{U2, U3}};
+    /// let (head, tail) = arr![1, 2, 3, 4, 5].split_pair::<U2, U3>();
+    ///
+    /// assert_eq!(head, arr![1, 2]);
+    /// assert_eq!(tail, arr![3, 4, 5]);
+    /// ```
+    ///
+    /// Lengths that do not sum to `N` will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U2};
+    /// let _ = arr![1, 2, 3, 4, 5].split_pair::<U2, U2>();
+    /// ```
+    #[inline]
+    pub fn split_pair<A, B>(self) -> (GenericArray<T, A>, GenericArray<T, B>)
+    where
+        A: ArrayLength + Add<B, Output = N>,
+        B: ArrayLength,
+    {
+        unsafe {
+            // ensure this doesn't get dropped
+            let whole = ManuallyDrop::new(self);
+
+            let head = ptr::read(whole.as_ptr() as *const _);
+            let tail = ptr::read(whole.as_ptr().add(A::USIZE) as *const _);
+
+            (head, tail)
+        }
+    }
+
+    /// Splits the array into mutable `C`-sized chunks, returning an iterator over the chunks
+    /// and the mutable remainder of `N % C` trailing elements that do not fill a chunk.
+    ///
+    /// The chunks and the remainder never overlap, so both may be mutated at the same time.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (chunks, rem) = a.chunks_with_remainder_mut::<U2>();
+    /// for chunk in chunks {
+    ///     chunk.swap(0, 1);
+    /// }
+    /// rem[0] = 0;
+    ///
+    /// assert_eq!(a, arr![2, 1, 4, 3, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is `U0` _AND_ the array is not empty.
+    #[inline]
+    pub fn chunks_with_remainder_mut<C: ArrayLength>(
+        &mut self,
+    ) -> (slice::IterMut<'_, GenericArray<T, C>>, &mut [T]) {
+        let (chunks, rem) = GenericArray::<T, C>::chunks_from_slice_mut(self);
+
+        (chunks.iter_mut(), rem)
+    }
+
+    /// Applies `f` in place to each consecutive `C`-sized chunk of the array, in order.
+    ///
+    /// `N` must be evenly divisible by `C`, which is checked at compile-time, so no elements are left over.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4, 5, 6];
+    ///
+    /// a.for_each_chunk_mut::<U2, _>(|chunk| chunk.swap(0, 1));
+    ///
+    /// assert_eq!(a, arr![2, 1, 4, 3, 6, 5]);
+    /// ```
+    ///
+    /// Chunk sizes that do not evenly divide the array length will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// a.for_each_chunk_mut::<U2, _>(|chunk| chunk.swap(0, 1));
+    /// ```
+    #[inline]
+    pub fn for_each_chunk_mut<C, F>(&mut self, f: F)
+    where
+        C: ArrayLength,
+        N: PartialDiv<C>,
+        F: FnMut(&mut GenericArray<T, C>),
+    {
+        GenericArray::<T, C>::chunks_from_slice_mut(self)
+            .0
+            .iter_mut()
+            .for_each(f);
+    }
+
+    /// Returns an iterator over `size` elements of the array at a time, starting at the beginning.
+    ///
+    /// The chunks are slices and do not overlap. If `size` does not divide the length of the array,
+    /// the last chunk will be shorter. This is equivalent to [`slice::chunks`].
+    ///
+    /// When the chunk size is known at compile-time, prefer
+    /// [`chunks_from_slice`](GenericArray::chunks_from_slice) or
+    /// [`chunks_with_remainder_mut`](GenericArray::chunks_with_remainder_mut), which yield
+    /// typed `GenericArray` chunks instead.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3, 4, 5];
+    /// let mut iter = a.chunks_runtime(2);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 4][..]));
+    /// assert_eq!(iter.next(), Some(&[5][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks_runtime(&self, size: usize) -> slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Returns an iterator over `size` elements of the array at a time as mutable slices,
+    /// starting at the beginning.
+    ///
+    /// See [`chunks_runtime`](GenericArray::chunks_runtime) for details. This is equivalent
+    /// to [`slice::chunks_mut`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// for chunk in a.chunks_runtime_mut(2) {
+    ///     chunk.reverse();
+    /// }
+    ///
+    /// assert_eq!(a, arr![2, 1, 4, 3, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks_runtime_mut(&mut self, size: usize) -> slice::ChunksMut<'_, T> {
+        self.as_mut_slice().chunks_mut(size)
+    }
+
+    /// Returns the first `C` elements of the array as a typed array reference along with
+    /// the remaining elements, or `None` if the array has fewer than `C` elements.
+    ///
+    /// This is the typed equivalent of [`slice::split_first_chunk`], which takes a const
+    /// length and remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::{U2, U6}};
+    /// let packet = arr![0xAB, 0xCD, 1, 2, 3];
+    ///
+    /// let (header, payload) = packet.try_split_first_chunk_typed::<U2>().unwrap();
+    /// assert_eq!(header, &arr![0xAB, 0xCD]);
+    /// assert_eq!(payload, &[1, 2, 3]);
+    ///
+    /// assert!(packet.try_split_first_chunk_typed::<U6>().is_none());
+    /// ```
+    #[inline]
+    pub fn try_split_first_chunk_typed<C: ArrayLength>(
+        &self,
+    ) -> Option<(&GenericArray<T, C>, &[T])> {
+        if N::USIZE < C::USIZE {
+            return None;
+        }
+
+        let (head, tail) = self.split_at(C::USIZE);
+
+        Some((GenericArray::from_slice(head), tail))
+    }
+
+    /// Returns the first `C` elements of the array as a mutable typed array reference along with
+    /// the remaining elements, or `None` if the array has fewer than `C` elements.
+    ///
+    /// This is the typed equivalent of [`slice::split_first_chunk_mut`], which takes a const
+    /// length and remains available through deref.
+    #[inline]
+    pub fn try_split_first_chunk_mut_typed<C: ArrayLength>(
+        &mut self,
+    ) -> Option<(&mut GenericArray<T, C>, &mut [T])> {
+        if N::USIZE < C::USIZE {
+            return None;
+        }
+
+        let (head, tail) = self.split_at_mut(C::USIZE);
+
+        Some((GenericArray::from_mut_slice(head), tail))
+    }
+
+    /// Returns the last `C` elements of the array as a typed array reference along with
+    /// the preceding elements, or `None` if the array has fewer than `C` elements.
+    ///
+    /// This is the typed equivalent of [`slice::split_last_chunk`], which takes a const
+    /// length and remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (init, last) = a.try_split_last_chunk_typed::<U2>().unwrap();
+    /// assert_eq!(init, &[1, 2, 3]);
+    /// assert_eq!(last, &arr![4, 5]);
+    /// ```
+    #[inline]
+    pub fn try_split_last_chunk_typed<C: ArrayLength>(
+        &self,
+    ) -> Option<(&[T], &GenericArray<T, C>)> {
+        if N::USIZE < C::USIZE {
+            return None;
+        }
+
+        let (init, last) = self.split_at(N::USIZE - C::USIZE);
+
+        Some((init, GenericArray::from_slice(last)))
+    }
+
+    /// Returns the last `C` elements of the array as a mutable typed array reference along with
+    /// the preceding elements, or `None` if the array has fewer than `C` elements.
+    ///
+    /// This is the typed equivalent of [`slice::split_last_chunk_mut`], which takes a const
+    /// length and remains available through deref.
+    #[inline]
+    pub fn try_split_last_chunk_mut_typed<C: ArrayLength>(
+        &mut self,
+    ) -> Option<(&mut [T], &mut GenericArray<T, C>)> {
+        if N::USIZE < C::USIZE {
+            return None;
+        }
+
+        let (init, last) = self.split_at_mut(N::USIZE - C::USIZE);
+
+        Some((init, GenericArray::from_mut_slice(last)))
+    }
+
+    /// Returns the first `C` elements of the array as a typed array reference along with
+    /// the remaining elements.
+    ///
+    /// This is the infallible equivalent of
+    /// [`try_split_first_chunk_typed`](GenericArray::try_split_first_chunk_typed),
+    /// with `C <= N` checked at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// let (head, tail) = a.split_first_chunk_typed::<U2>();
+    /// assert_eq!(head, &arr![1, 2]);
+    /// assert_eq!(tail, &[3]);
+    /// ```
+    #[inline]
+    pub fn split_first_chunk_typed<C>(&self) -> (&GenericArray<T, C>, &[T])
+    where
+        C: ArrayLength,
+        N: IsGreaterOrEqual<C, Output = True>,
+    {
+        let (head, tail) = self.split_at(C::USIZE);
+
+        (GenericArray::from_slice(head), tail)
+    }
+
+    /// Consumes the array, regrouping its elements into an array of `C`-sized chunks.
+    ///
+    /// The [`PartialDiv`] bound is only satisfied when `C` evenly divides `N`, so any other
+    /// chunk size (including `U0`) is rejected at compile-time rather than at runtime.
+    /// Elements keep their order and are not moved, only regrouped.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4, 5, 6];
+    ///
+    /// assert_eq!(a.into_array_of_chunks::<U2>(), arr![arr![1, 2], arr![3, 4], arr![5, 6]]);
+    /// ```
+    ///
+    /// Chunk sizes that do not evenly divide the array length will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U4};
+    /// let a = arr![1, 2, 3, 4, 5, 6];
+    ///
+    /// let _ = a.into_array_of_chunks::<U4>();
+    /// ```
+    #[inline(always)]
+    pub const fn into_array_of_chunks<C>(
+        self,
+    ) -> GenericArray<GenericArray<T, C>, PartialQuot<N, C>>
+    where
+        C: ArrayLength,
+        N: PartialDiv<C>,
+        PartialQuot<N, C>: ArrayLength,
+    {
+        unsafe { const_transmute(self) }
+    }
+
+    /// Splits the array into mutable `C`-sized chunks, returning a [`ChunksMut`] iterator over
+    /// the chunks and the mutable remainder of `N % C` trailing elements that do not fill a chunk.
+    ///
+    /// Unlike [`chunks_with_remainder_mut`](GenericArray::chunks_with_remainder_mut), the iterator
+    /// is a dedicated type that can be stored and advanced independently of the remainder.
+    ///
+    /// ```
+    /// # use generic_array::{arr, ChunksMut, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (mut chunks, rem): (ChunksMut<'_, i32, U2>, _) = a.chunks_mut_typed();
+    ///
+    /// chunks.next().unwrap()[0] = 10;
+    /// chunks.next_back().unwrap()[1] = 40;
+    /// rem[0] = 50;
+    ///
+    /// assert!(chunks.next().is_none());
+    /// assert_eq!(a, arr![10, 2, 3, 40, 50]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is `U0` _AND_ the array is not empty.
+    #[inline]
+    pub fn chunks_mut_typed<C: ArrayLength>(&mut self) -> (ChunksMut<'_, T, C>, &mut [T]) {
+        let (chunks, rem) = GenericArray::<T, C>::chunks_from_slice_mut(self);
+
+        (
+            ChunksMut {
+                inner: chunks.iter_mut(),
+            },
+            rem,
+        )
+    }
+
+    /// Returns an iterator over all contiguous windows of `W` elements, as typed array references.
+    ///
+    /// The windows overlap, and there are `N - W + 1` of them. If `W > N`, no windows are yielded.
+    /// This is the typed equivalent of [`slice::windows`].
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4];
+    /// let mut iter = a.array_windows::<U2>();
+    ///
+    /// assert_eq!(iter.next(), Some(&arr![1, 2]));
+    /// assert_eq!(iter.next(), Some(&arr![2, 3]));
+    /// assert_eq!(iter.next(), Some(&arr![3, 4]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` is `U0`.
+    #[inline]
+    pub fn array_windows<W: ArrayLength>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &GenericArray<T, W>> + ExactSizeIterator + '_ {
+        assert!(W::USIZE != 0, "window size W must be non-zero");
+
+        let slice = self.as_slice();
+
+        (0..(N::USIZE + 1).saturating_sub(W::USIZE))
+            .map(move |i| GenericArray::from_slice(&slice[i..i + W::USIZE]))
+    }
+
+    /// Returns an iterator over all contiguous windows of `W` elements, as mutable typed array references.
+    ///
+    /// The windows overlap, and there are `N - W + 1` of them. If `W > N`, no windows are yielded.
+    /// This is the mutable counterpart to [`array_windows`](GenericArray::array_windows).
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let mut a = arr![1, 2, 3, 4];
+    ///
+    /// // relax each element towards its right neighbor, one window at a time
+    /// for w in unsafe { a.windows_mut_unchecked::<U2>() } {
+    ///     w[1] += w[0];
+    /// }
+    ///
+    /// assert_eq!(a, arr![1, 3, 6, 10]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Consecutive windows alias each other, which the borrow checker cannot see.
+    /// Holding two overlapping `&mut` references at once is undefined behavior
+    /// as soon as either one is used, so the caller must ensure that:
+    ///
+    /// * a window is no longer used (read or written) once any later window overlapping it has been yielded,
+    /// * windows are not collected or otherwise kept alive alongside overlapping windows.
+    ///
+    /// Processing each window fully before advancing the iterator, as in the example above, is sound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` is `U0`.
+    #[cfg(feature = "internals")]
+    #[inline]
+    pub unsafe fn windows_mut_unchecked<W: ArrayLength>(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut GenericArray<T, W>> + ExactSizeIterator + '_ {
+        assert!(W::USIZE != 0, "window size W must be non-zero");
+
+        let ptr = self.as_mut_ptr();
+
+        (0..(N::USIZE + 1).saturating_sub(W::USIZE))
+            .map(move |i| unsafe { &mut *(ptr.add(i) as *mut GenericArray<T, W>) })
+    }
+
+    /// Splits the array into `C`-sized chunks starting from the end, returning an iterator over
+    /// the chunks in reverse order and the leading remainder of `N % C` elements that do not fill a chunk.
+    ///
+    /// This is the typed equivalent of [`slice::rchunks_exact`], where any short chunk is at the front.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (mut chunks, rem) = a.rchunks_with_remainder::<U2>();
+    ///
+    /// assert_eq!(chunks.next(), Some(&arr![4, 5]));
+    /// assert_eq!(chunks.next(), Some(&arr![2, 3]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(rem, &[1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is `U0` _AND_ the array is not empty.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn rchunks_with_remainder<C: ArrayLength>(
+        &self,
+    ) -> (Rev<slice::Iter<'_, GenericArray<T, C>>>, &[T]) {
+        let rem_len = match C::USIZE {
+            0 => 0,
+            c => N::USIZE % c,
+        };
+
+        let (rem, body) = self.split_at(rem_len);
+        let (chunks, _) = GenericArray::<T, C>::chunks_from_slice(body);
+
+        (chunks.iter().rev(), rem)
+    }
+
+    /// Splits the array into a leading remainder of `N % C` elements, followed by
+    /// an array of `N / C` typed chunks aligned to the end of the array.
+    ///
+    /// This is the typed equivalent of the unstable `slice::as_rchunks`. Unlike
+    /// [`rchunks_with_remainder`](GenericArray::rchunks_with_remainder), the chunks are returned
+    /// in their original order, and their count is known at compile-time. A chunk size
+    /// of `U0` will not compile.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// let (rem, chunks) = a.as_rchunks::<U2>();
+    ///
+    /// assert_eq!(rem, &[1]);
+    /// assert_eq!(chunks, &arr![arr![2, 3], arr![4, 5]]);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn as_rchunks<C>(&self) -> (&[T], &GenericArray<GenericArray<T, C>, Quot<N, C>>)
+    where
+        C: ArrayLength,
+        N: Div<C>,
+        Quot<N, C>: ArrayLength,
+    {
+        let (rem, body) = self.split_at(N::USIZE % C::USIZE);
+        let (chunks, _) = GenericArray::<T, C>::chunks_from_slice(body);
+
+        (rem, GenericArray::from_slice(chunks))
+    }
+
+    /// Returns the first `C` elements of the array as a typed array reference,
+    /// with `C <= N` checked at compile-time.
+    ///
+    /// This is the typed equivalent of [`slice::first_chunk`], which remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// assert_eq!(a.first_chunk_typed::<U2>(), &arr![1, 2]);
+    /// ```
+    ///
+    /// Chunks longer than the array will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U4};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// let _ = a.first_chunk_typed::<U4>();
+    /// ```
+    #[inline]
+    pub fn first_chunk_typed<C>(&self) -> &GenericArray<T, C>
+    where
+        C: ArrayLength,
+        N: IsGreaterOrEqual<C, Output = True>,
+    {
+        GenericArray::from_slice(&self[..C::USIZE])
+    }
+
+    /// Returns the first `C` elements of the array as a mutable typed array reference,
+    /// with `C <= N` checked at compile-time.
+    ///
+    /// This is the typed equivalent of [`slice::first_chunk_mut`], which remains available through deref.
+    #[inline]
+    pub fn first_chunk_mut_typed<C>(&mut self) -> &mut GenericArray<T, C>
+    where
+        C: ArrayLength,
+        N: IsGreaterOrEqual<C, Output = True>,
+    {
+        GenericArray::from_mut_slice(&mut self[..C::USIZE])
+    }
+
+    /// Returns the last `C` elements of the array as a typed array reference,
+    /// with `C <= N` checked at compile-time.
+    ///
+    /// This is the typed equivalent of [`slice::last_chunk`], which remains available through deref.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// assert_eq!(a.last_chunk_typed::<U2>(), &arr![2, 3]);
+    /// ```
+    ///
+    /// Chunks longer than the array will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U4};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// let _ = a.last_chunk_typed::<U4>();
+    /// ```
+    #[inline]
+    pub fn last_chunk_typed<C>(&self) -> &GenericArray<T, C>
+    where
+        C: ArrayLength,
+        N: IsGreaterOrEqual<C, Output = True>,
+    {
+        GenericArray::from_slice(&self[N::USIZE - C::USIZE..])
+    }
+
+    /// Returns the last `C` elements of the array as a mutable typed array reference,
+    /// with `C <= N` checked at compile-time.
+    ///
+    /// This is the typed equivalent of [`slice::last_chunk_mut`], which remains available through deref.
+    #[inline]
+    pub fn last_chunk_mut_typed<C>(&mut self) -> &mut GenericArray<T, C>
+    where
+        C: ArrayLength,
+        N: IsGreaterOrEqual<C, Output = True>,
+    {
+        GenericArray::from_mut_slice(&mut self[N::USIZE - C::USIZE..])
+    }
+
+    /// Splits the array into two halves.
+    ///
+    /// For even `N`, both halves have `N / 2` elements. For odd `N`, the first half
+    /// has the extra element, with `(N + 1) / 2` elements.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![1, 2, 3, 4].halves(), (&[1, 2][..], &[3, 4][..]));
+    /// assert_eq!(arr![1, 2, 3].halves(), (&[1, 2][..], &[3][..]));
+    /// ```
+    #[inline]
+    pub fn halves(&self) -> (&[T], &[T]) {
+        self.split_at(N::USIZE.div_ceil(2))
+    }
+
+    /// Swaps the first and second halves of an even-length array in place.
+    ///
+    /// This is equivalent to `rotate_left(N / 2)`, but the evenness of `N` is checked at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4, 5, 6];
+    /// a.swap_halves();
+    ///
+    /// assert_eq!(a, arr![4, 5, 6, 1, 2, 3]);
+    /// ```
+    ///
+    /// Odd lengths are rejected at compile-time:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3];
+    /// a.swap_halves();
+    /// ```
+    #[inline]
+    pub fn swap_halves(&mut self)
+    where
+        N: PartialDiv<U2>,
+    {
+        let (first, second) = self.split_at_mut(N::USIZE / 2);
+        first.swap_with_slice(second);
+    }
+}
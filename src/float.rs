@@ -0,0 +1,74 @@
+//! Floating-point operations for `GenericArray`
+
+use crate::sequence::GenericSequence;
+use crate::{ArrayLength, GenericArray};
+
+macro_rules! impl_float {
+    ($($t:ty => $tiny:literal),*) => {$(
+        impl<N: ArrayLength> GenericArray<$t, N> {
+            /// Sums the elements of the array using Kahan compensated summation.
+            ///
+            /// The rounding error of each addition is tracked and fed back into the next,
+            /// so the result is far less sensitive to the length of the array and the
+            /// order of its elements than [`Iterator::sum`]. In exchange, it performs
+            /// four floating-point operations per element instead of one,
+            /// and does not vectorize as well.
+            ///
+            /// ```
+            /// # use generic_array::{GenericArray, sequence::GenericSequence, typenum::U64};
+            #[doc = concat!("let a = GenericArray::<", stringify!($t), ", U64>::generate(|i| if i == 0 { 1.0 } else { ", stringify!($tiny), " });")]
+            ///
+            /// // each tiny element is rounded away by naive summation
+            #[doc = concat!("assert_eq!(a.iter().sum::<", stringify!($t), ">(), 1.0);")]
+            /// assert!(a.sum_kahan() > 1.0);
+            /// ```
+            #[inline]
+            pub fn sum_kahan(&self) -> $t {
+                let mut sum: $t = 0.0;
+                let mut compensation: $t = 0.0;
+
+                for &x in self.iter() {
+                    let y = x - compensation;
+                    let t = sum + y;
+
+                    compensation = (t - sum) - y;
+                    sum = t;
+                }
+
+                sum
+            }
+
+            /// Linearly interpolates between `self` and `other` element-wise by the factor `t`,
+            /// so that `t = 0.0` gives `self` and `t = 1.0` gives `other`.
+            ///
+            /// Each element is computed as `a + (b - a) * t` for `t < 0.5`, and as
+            /// `b - (b - a) * (1.0 - t)` otherwise. Anchoring each half to its nearest
+            /// endpoint makes both endpoints exact, even when `a` and `b` differ greatly in magnitude.
+            /// Values of `t` outside `0.0..=1.0` extrapolate.
+            ///
+            /// ```
+            /// # use generic_array::arr;
+            #[doc = concat!("let a = arr![0.0", stringify!($t), ", 10.0, -4.0];")]
+            /// let b = arr![1.0, 20.0, 4.0];
+            ///
+            /// assert_eq!(a.lerp(&b, 0.0), a);
+            /// assert_eq!(a.lerp(&b, 0.5), arr![0.5, 15.0, 0.0]);
+            /// assert_eq!(a.lerp(&b, 1.0), b);
+            /// ```
+            #[inline]
+            pub fn lerp(&self, other: &Self, t: $t) -> Self {
+                GenericArray::generate(|i| {
+                    let (a, b) = (self[i], other[i]);
+
+                    if t < 0.5 {
+                        a + (b - a) * t
+                    } else {
+                        b - (b - a) * (1.0 - t)
+                    }
+                })
+            }
+        }
+    )*};
+}
+
+impl_float!(f32 => 1e-8, f64 => 1e-17);
@@ -1,6 +1,8 @@
 //! Generic array are commonly used as a return value for hash digests, so
 //! it's a good idea to allow to hexlify them easily. This module implements
-//! `std::fmt::LowerHex` and `std::fmt::UpperHex` traits.
+//! `std::fmt::LowerHex` and `std::fmt::UpperHex` traits, along with the
+//! [`HexDebug`](crate::HexDebug) wrapper for `Debug` output, and parsing
+//! with [`from_hex`](GenericArray::from_hex).
 //!
 //! Example:
 //!
@@ -126,3 +128,143 @@ where
         generic_hex::<_, true>(self, f)
     }
 }
+
+/// Wrapper around a byte array reference that formats it as a lowercase hex string
+/// with [`Debug`](fmt::Debug), matching its [`LowerHex`](fmt::LowerHex) output.
+///
+/// This is useful for printing keys and digests, or deriving `Debug` on types containing them,
+/// without changing the default `Debug` output of `GenericArray`.
+///
+/// ```rust
+/// use generic_array::{arr, HexDebug};
+///
+/// let key = arr![0xDEu8, 0xAD, 0xBE, 0xEF];
+///
+/// assert_eq!(format!("{:?}", HexDebug(&key)), "deadbeef");
+/// assert_eq!(format!("{:?}", key), "[222, 173, 190, 239]");
+/// ```
+#[derive(Clone, Copy)]
+pub struct HexDebug<'a, N: ArrayLength>(pub &'a GenericArray<u8, N>);
+
+impl<N: ArrayLength> fmt::Debug for HexDebug<'_, N>
+where
+    N: Add<N>,
+    Sum<N, N>: ArrayLength,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        generic_hex::<_, false>(self.0, f)
+    }
+}
+
+/// Error for [`from_hex`](GenericArray::from_hex) and [`from_hex_lenient`](GenericArray::from_hex_lenient)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The input did not contain exactly two hex digits per array element.
+    Length,
+    /// The character at byte offset `index` is not a hex digit or permitted whitespace.
+    InvalidChar {
+        /// Byte offset of the invalid character in the input
+        index: usize,
+    },
+    /// Whitespace at byte offset `index` splits the two hex digits of a single byte.
+    SplitByte {
+        /// Byte offset of the splitting whitespace in the input
+        index: usize,
+    },
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexError::Length => {
+                f.write_str("HexError: Number of hex digits does not match GenericArray length")
+            }
+            HexError::InvalidChar { index } => {
+                write!(f, "HexError: Invalid character at index {}", index)
+            }
+            HexError::SplitByte { index } => {
+                write!(f, "HexError: Whitespace within a byte at index {}", index)
+            }
+        }
+    }
+}
+
+#[inline]
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn hex_decode(src: &str, dst: &mut [u8], lenient: bool) -> Result<(), HexError> {
+    let mut high = None;
+    let mut len = 0;
+
+    for (index, c) in src.bytes().enumerate() {
+        if lenient && c.is_ascii_whitespace() {
+            match high {
+                Some(_) => return Err(HexError::SplitByte { index }),
+                None => continue,
+            }
+        }
+
+        let nibble = hex_digit(c).ok_or(HexError::InvalidChar { index })?;
+
+        match high.take() {
+            None => high = Some(nibble),
+            Some(high) => {
+                *dst.get_mut(len).ok_or(HexError::Length)? = (high << 4) | nibble;
+                len += 1;
+            }
+        }
+    }
+
+    match high.is_none() && len == dst.len() {
+        true => Ok(()),
+        false => Err(HexError::Length),
+    }
+}
+
+impl<N: ArrayLength> GenericArray<u8, N> {
+    /// Parses a byte array from a string of exactly `2 * N` hex digits.
+    ///
+    /// Both uppercase and lowercase digits are accepted, and no prefix or whitespace is allowed.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, HexError, typenum::U3};
+    /// type A = GenericArray<u8, U3>;
+    ///
+    /// assert_eq!(A::from_hex("0a141E"), Ok(arr![10, 20, 30]));
+    /// assert_eq!(A::from_hex("0a14"), Err(HexError::Length));
+    /// assert_eq!(A::from_hex("0a 14 1e"), Err(HexError::InvalidChar { index: 2 }));
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let mut array = GenericArray::default();
+        hex_decode(s, &mut array, false)?;
+        Ok(array)
+    }
+
+    /// Parses a byte array from a string of exactly `2 * N` hex digits,
+    /// ignoring any ASCII whitespace between bytes.
+    ///
+    /// This accepts hex dumps spread across multiple lines or grouped with spaces.
+    /// Whitespace is only permitted between pairs of digits, so a byte split
+    /// in the middle such as `"a b"` is rejected with [`HexError::SplitByte`].
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, HexError, typenum::U4};
+    /// let a = GenericArray::<u8, U4>::from_hex_lenient("de ad\n  beef\n");
+    /// assert_eq!(a, Ok(arr![0xDE, 0xAD, 0xBE, 0xEF]));
+    ///
+    /// let a = GenericArray::<u8, U4>::from_hex_lenient("dea dbeef");
+    /// assert_eq!(a, Err(HexError::SplitByte { index: 3 }));
+    /// ```
+    pub fn from_hex_lenient(s: &str) -> Result<Self, HexError> {
+        let mut array = GenericArray::default();
+        hex_decode(s, &mut array, true)?;
+        Ok(array)
+    }
+}
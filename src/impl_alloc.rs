@@ -1,6 +1,6 @@
 use alloc::{boxed::Box, vec::Vec};
 
-use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder, LengthError};
+use crate::{ArrayConsumer, ArrayLength, GenericArray, IntrusiveArrayBuilder, LengthError};
 
 impl<T, N: ArrayLength> TryFrom<Vec<T>> for GenericArray<T, N> {
     type Error = crate::LengthError;
@@ -107,6 +107,129 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
 
         Ok(GenericArray::try_from_vec(v).unwrap())
     }
+
+    /// Consumes the array, moving every element for which `f` returns `true` into a `Vec`,
+    /// in order, and dropping the rest.
+    ///
+    /// No elements are cloned. If `f` panics, the element being tested and any unused
+    /// elements will be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let evens = arr![1, 2, 3, 4, 5, 6].filter(|x| x % 2 == 0);
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// ```
+    pub fn filter<F>(self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept = Vec::new();
+
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+
+            let (array_iter, position) = source.iter_position();
+
+            for src in array_iter {
+                let value = core::ptr::read(src);
+
+                *position += 1;
+
+                if f(&value) {
+                    kept.push(value);
+                }
+            }
+        }
+
+        kept
+    }
+
+    /// Consumes the array, splitting it into `C`-sized chunks. If `N` is not evenly divisible by `C`,
+    /// the final chunk is padded with clones of `pad`.
+    ///
+    /// This produces `ceil(N / C)` chunks.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U3};
+    /// let chunks = arr![1, 2, 3, 4].into_padded_chunks::<U3>(0);
+    /// assert_eq!(chunks, [arr![1, 2, 3], arr![4, 0, 0]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is `U0` _AND_ the array is not empty.
+    pub fn into_padded_chunks<C: ArrayLength>(self, pad: T) -> Vec<GenericArray<T, C>>
+    where
+        T: Clone,
+    {
+        if C::USIZE == 0 {
+            assert!(
+                N::USIZE == 0,
+                "GenericArray chunk length C must be non-zero"
+            );
+            return Vec::new();
+        }
+
+        let num_chunks = N::USIZE.div_ceil(C::USIZE);
+
+        let mut iter = self.into_iter();
+        let mut chunks = Vec::with_capacity(num_chunks);
+
+        for _ in 0..num_chunks {
+            chunks.push(GenericArray::generate(|_| {
+                iter.next().unwrap_or_else(|| pad.clone())
+            }));
+        }
+
+        chunks
+    }
+
+    /// Clones the elements of the array followed by the elements of `other` into a new `Vec`.
+    ///
+    /// See [`try_concat_to`](GenericArray::try_concat_to) for when the combined length is known.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![1, 2].concat_slice(&[3, 4, 5]), [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn concat_slice(&self, other: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(N::USIZE + other.len());
+
+        vec.extend_from_slice(self);
+        vec.extend_from_slice(other);
+
+        vec
+    }
+}
+
+impl<C: ArrayLength, R: ArrayLength> GenericArray<GenericArray<u8, C>, R> {
+    /// Concatenates the inner byte arrays into a new `Vec`, with `sep` placed between each one.
+    ///
+    /// This is useful for formatting fixed-size records into a delimited buffer.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let fields = arr![arr![b'a', b'b'], arr![b'c', b'd'], arr![b'e', b'f']];
+    ///
+    /// assert_eq!(fields.join_with(b", "), b"ab, cd, ef");
+    /// ```
+    pub fn join_with(&self, sep: &[u8]) -> Vec<u8> {
+        let mut vec =
+            Vec::with_capacity(R::USIZE * C::USIZE + R::USIZE.saturating_sub(1) * sep.len());
+
+        for (i, chunk) in self.iter().enumerate() {
+            if i > 0 {
+                vec.extend_from_slice(sep);
+            }
+
+            vec.extend_from_slice(chunk);
+        }
+
+        vec
+    }
 }
 
 impl<T, N: ArrayLength> TryFrom<Box<[T]>> for GenericArray<T, N> {
@@ -196,6 +319,33 @@ unsafe impl<T, N: ArrayLength> GenericSequence<T> for Box<GenericArray<T, N>> {
             Box::from_raw(ptr.cast()) // IntrusiveArrayBuilder::array_assume_init
         }
     }
+
+    fn try_generate<E, F>(mut f: F) -> Result<Self::Sequence, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        use core::mem::MaybeUninit;
+
+        unsafe {
+            // SAFETY: An uninitialized `[MaybeUninit<_>; N]` is valid, and will
+            // be deallocated without dropping any elements on error
+            let mut array = Box::<GenericArray<MaybeUninit<T>, N>>::new_uninit().assume_init();
+            let mut builder = IntrusiveArrayBuilder::new(&mut *array);
+
+            {
+                let (builder_iter, position) = builder.iter_position();
+
+                for (i, dst) in builder_iter.enumerate() {
+                    dst.write(f(i)?);
+                    *position += 1;
+                }
+            }
+
+            builder.finish();
+
+            Ok(Box::from_raw(Box::into_raw(array).cast())) // IntrusiveArrayBuilder::array_assume_init
+        }
+    }
 }
 
 impl<T, U, N: ArrayLength> MappedGenericSequence<T, U> for Box<GenericArray<T, N>> {
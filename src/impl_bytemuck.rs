@@ -0,0 +1,50 @@
+//! Byte-level access for arrays of `bytemuck::Pod` elements
+
+use crate::{ArrayLength, GenericArray};
+
+use bytemuck::Pod;
+
+impl<T: Pod, N: ArrayLength> GenericArray<T, N> {
+    /// Views the entire array as a slice of bytes, in the platform's native layout.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Views the entire array as a mutable slice of bytes, in the platform's native layout.
+    ///
+    /// As `T: Pod` has no invalid bit patterns, any bytes written are valid for `T`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![0x0102u16, 0x0304];
+    ///
+    /// // swap the byte order of each element in-place
+    /// a.as_bytes_mut().chunks_exact_mut(2).for_each(|c| c.reverse());
+    ///
+    /// assert_eq!(a, arr![0x0201, 0x0403]);
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_as_bytes() {
+        let mut array = arr![0x01020304u32, 0x05060708];
+
+        assert_eq!(array.as_bytes().len(), 8);
+        assert_eq!(array.as_bytes()[..4], 0x01020304u32.to_ne_bytes());
+
+        array.as_bytes_mut()[4..].copy_from_slice(&0xAABBCCDDu32.to_ne_bytes());
+        assert_eq!(array, arr![0x01020304, 0xAABBCCDD]);
+
+        array.as_bytes_mut().fill(0xFF);
+        assert_eq!(array, arr![u32::MAX, u32::MAX]);
+    }
+}
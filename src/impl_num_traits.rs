@@ -0,0 +1,290 @@
+//! Vector and matrix operations on `GenericArray` elements using `num-traits`
+
+use core::ops::{Add, Mul, Sub};
+
+use num_traits::{Float, One, PrimInt, Zero};
+use typenum::U3;
+
+use crate::functional::FunctionalSequence;
+use crate::sequence::GenericSequence;
+use crate::{ArrayLength, GenericArray};
+
+impl<T: Float, N: ArrayLength> GenericArray<T, N> {
+    /// Computes the squared Euclidean (L2) norm of the array, the sum of the squares of its elements.
+    ///
+    /// This avoids the square root of [`norm`](GenericArray::norm), which makes it cheaper
+    /// for comparing lengths.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![3.0, 4.0].norm_squared(), 25.0);
+    /// ```
+    #[inline]
+    pub fn norm_squared(&self) -> T {
+        self.iter().fold(T::zero(), |acc, &x| acc + x * x)
+    }
+
+    /// Computes the Euclidean (L2) norm of the array, treating it as a vector.
+    ///
+    /// The squares are summed directly, so very large or very small elements
+    /// may overflow to infinity or underflow to zero.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![3.0, 4.0].norm(), 5.0);
+    /// ```
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Consumes the array, dividing each element by the [`norm`](GenericArray::norm)
+    /// to produce a unit vector with the same direction.
+    ///
+    /// A zero vector has no direction, so it is returned unchanged as all zeros rather than
+    /// dividing by zero. Any `NaN` elements result in all `NaN` elements.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![3.0, 4.0].normalize(), arr![0.6, 0.8]);
+    /// assert_eq!(arr![0.0, 0.0].normalize(), arr![0.0, 0.0]);
+    /// ```
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let norm = self.norm();
+
+        if norm.is_zero() {
+            return self;
+        }
+
+        self.map(|x| x / norm)
+    }
+}
+
+impl<T> GenericArray<T, U3> {
+    /// Computes the cross product of two 3-dimensional vectors.
+    ///
+    /// The result is perpendicular to both inputs, following the right-hand rule,
+    /// and is anti-commutative, so `a.cross(&b) == -b.cross(&a)`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let x = arr![1, 0, 0];
+    /// let y = arr![0, 1, 0];
+    ///
+    /// assert_eq!(x.cross(&y), arr![0, 0, 1]);
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Copy,
+    {
+        let [a1, a2, a3] = [self[0], self[1], self[2]];
+        let [b1, b2, b3] = [other[0], other[1], other[2]];
+
+        GenericArray::from_array([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1])
+    }
+}
+
+impl<T: PrimInt, N: ArrayLength> GenericArray<T, N> {
+    /// Converts each element to another integer type, clamping values outside
+    /// the range of `U` to its minimum or maximum instead of wrapping.
+    ///
+    /// This is useful for reducing bit depth, such as converting intermediate
+    /// `i32` pixel values back into `u8`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![-20i32, 0, 100, 300];
+    ///
+    /// assert_eq!(a.saturating_cast::<u8>(), arr![0, 0, 100, 255]);
+    /// assert_eq!(a.saturating_cast::<i8>(), arr![-20, 0, 100, 127]);
+    /// ```
+    #[inline]
+    pub fn saturating_cast<U: PrimInt>(self) -> GenericArray<U, N> {
+        self.map(|x| match U::from(x) {
+            Some(y) => y,
+            None if x < T::zero() => U::min_value(),
+            None => U::max_value(),
+        })
+    }
+}
+
+impl<T, K: ArrayLength, M: ArrayLength> GenericArray<GenericArray<T, K>, M> {
+    /// Multiplies this `M`x`K` matrix by a `K`x`N` matrix, producing an `M`x`N` matrix,
+    /// where each inner array is a row.
+    ///
+    /// The inner dimensions must match, which is checked at compile-time.
+    /// This uses the naive `O(M * N * K)` algorithm, suited to small matrices.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![arr![1, 2], arr![3, 4]];
+    /// let b = arr![arr![5, 6], arr![7, 8]];
+    ///
+    /// assert_eq!(a.matmul(&b), arr![arr![19, 22], arr![43, 50]]);
+    /// ```
+    #[inline]
+    pub fn matmul<N: ArrayLength>(
+        &self,
+        rhs: &GenericArray<GenericArray<T, N>, K>,
+    ) -> GenericArray<GenericArray<T, N>, M>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+    {
+        GenericArray::generate(|i| {
+            GenericArray::generate(|j| {
+                self[i]
+                    .iter()
+                    .zip(rhs.iter())
+                    .fold(T::zero(), |acc, (&x, row)| acc + x * row[j])
+            })
+        })
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<GenericArray<T, N>, N> {
+    /// Creates the `N`x`N` identity matrix, with [`One`] on the diagonal and [`Zero`] elsewhere.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U3};
+    /// let id = GenericArray::<GenericArray<i32, U3>, U3>::identity();
+    ///
+    /// assert_eq!(id, arr![arr![1, 0, 0], arr![0, 1, 0], arr![0, 0, 1]]);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        GenericArray::generate(|i| {
+            GenericArray::generate(|j| if i == j { T::one() } else { T::zero() })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_norm() {
+        let a = arr![1.0f64, -2.0, 2.0];
+
+        assert_eq!(a.norm_squared(), 9.0);
+        assert_eq!(a.norm(), 3.0);
+        assert_eq!(arr![0.0f32; typenum::U0].norm(), 0.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = arr![2.0f64, -4.0, 4.0].normalize();
+
+        assert_eq!(a, arr![1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0]);
+        assert!((a.norm() - 1.0).abs() < f64::EPSILON);
+
+        let b = arr![1.0f32; typenum::U16].normalize();
+        assert!(b.iter().all(|&x| x == 0.25));
+
+        assert_eq!(arr![0.0f32, -0.0, 0.0].normalize(), arr![0.0, 0.0, 0.0]);
+        assert!(arr![1.0f32, f32::NAN]
+            .normalize()
+            .iter()
+            .all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = arr![1, 0, 0];
+        let y = arr![0, 1, 0];
+        let z = arr![0, 0, 1];
+
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(z.cross(&x), y);
+        assert_eq!(x.cross(&x), arr![0, 0, 0]);
+
+        let a = arr![2.0, -1.0, 3.5];
+        let b = arr![0.5, 4.0, -2.0];
+        let c = a.cross(&b);
+
+        assert_eq!(c, arr![-12.0, 5.75, 8.5]);
+        assert_eq!(c, b.cross(&a).map(|x: f64| -x));
+
+        // perpendicular to both inputs
+        let dot = |u: &GenericArray<f64, typenum::U3>, v: &GenericArray<f64, typenum::U3>| {
+            u.iter().zip(v.iter()).map(|(p, q)| p * q).sum::<f64>()
+        };
+        assert_eq!(dot(&c, &a), 0.0);
+        assert_eq!(dot(&c, &b), 0.0);
+    }
+
+    #[test]
+    fn test_matmul() {
+        let a = arr![arr![1, 2, 3], arr![4, 5, 6]];
+        let b = arr![arr![7, 8], arr![9, 10], arr![11, 12]];
+
+        assert_eq!(a.matmul(&b), arr![arr![58, 64], arr![139, 154]]);
+        assert_eq!(
+            b.matmul(&a),
+            arr![arr![39, 54, 69], arr![49, 68, 87], arr![59, 82, 105]]
+        );
+
+        // row vector times column vector
+        let row = arr![arr![1.5, 2.0]];
+        let col = arr![arr![2.0], arr![4.0]];
+        assert_eq!(row.matmul(&col), arr![arr![11.0]]);
+
+        // an empty inner dimension gives all zeros
+        let empty_k = GenericArray::<GenericArray<i32, typenum::U0>, typenum::U2>::default();
+        let empty_rows = GenericArray::<GenericArray<i32, typenum::U3>, typenum::U0>::default();
+        assert_eq!(
+            empty_k.matmul(&empty_rows),
+            arr![arr![0, 0, 0], arr![0, 0, 0]]
+        );
+    }
+
+    #[test]
+    fn test_identity() {
+        type Mat<R, C> = GenericArray<GenericArray<i64, C>, R>;
+
+        let a: Mat<typenum::U2, typenum::U3> = arr![arr![1, -2, 3], arr![4, 5, -6]];
+
+        assert_eq!(Mat::<typenum::U2, typenum::U2>::identity().matmul(&a), a);
+        assert_eq!(a.matmul(&Mat::<typenum::U3, typenum::U3>::identity()), a);
+
+        let id = Mat::<typenum::U4, typenum::U4>::identity();
+        assert_eq!(id.matmul(&id), id);
+        assert_eq!(
+            GenericArray::<GenericArray<f32, typenum::U1>, typenum::U1>::identity(),
+            arr![arr![1.0]]
+        );
+    }
+
+    #[test]
+    fn test_saturating_cast() {
+        let a = arr![i32::MIN, -129, -128, -1, 0, 127, 128, 255, 256, i32::MAX];
+
+        assert_eq!(
+            a.saturating_cast::<u8>(),
+            arr![0, 0, 0, 0, 0, 127, 128, 255, 255, 255]
+        );
+        assert_eq!(
+            a.saturating_cast::<i8>(),
+            arr![-128, -128, -128, -1, 0, 127, 127, 127, 127, 127]
+        );
+        assert_eq!(a.saturating_cast::<i64>(), a.map(i64::from));
+
+        let b = arr![0u64, u32::MAX as u64, u64::MAX];
+        assert_eq!(b.saturating_cast::<u32>(), arr![0, u32::MAX, u32::MAX]);
+        assert_eq!(
+            b.saturating_cast::<i64>(),
+            arr![0, u32::MAX as i64, i64::MAX]
+        );
+        assert_eq!(b.saturating_cast::<i128>(), b.map(i128::from));
+
+        let c = arr![i128::MIN, -1, i128::MAX];
+        assert_eq!(c.saturating_cast::<u128>(), arr![0, 0, i128::MAX as u128]);
+        assert_eq!(c.saturating_cast::<i16>(), arr![i16::MIN, -1, i16::MAX]);
+    }
+}
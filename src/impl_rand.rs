@@ -0,0 +1,144 @@
+//! Random shuffling and sampling of `GenericArray` elements using `rand`
+
+use crate::sequence::GenericSequence;
+use crate::{ArrayLength, GenericArray};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use typenum::{IsLessOrEqual, True};
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Shuffles the elements of the array in-place using the Fisher-Yates algorithm.
+    ///
+    /// This is equivalent to [`SliceRandom::shuffle`] on the array's slice.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// a.shuffle(&mut rand::rng());
+    ///
+    /// a.sort();
+    /// assert_eq!(a, arr![1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.as_mut_slice().shuffle(rng);
+    }
+
+    /// Consumes the array and returns it with its elements shuffled.
+    ///
+    /// See [`shuffle`](GenericArray::shuffle) for details. Should the random number
+    /// generator panic, all elements of the array are dropped.
+    #[inline]
+    pub fn shuffled<R: Rng + ?Sized>(mut self, rng: &mut R) -> Self {
+        self.shuffle(rng);
+        self
+    }
+
+    /// Randomly chooses `M` distinct elements of the array, cloning them into a new array.
+    ///
+    /// Each element is chosen at most once, and every subset of `M` elements is equally likely,
+    /// as is every order of the chosen elements. `M <= N` is checked at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let pool = arr![10, 20, 30, 40];
+    ///
+    /// let picked = pool.sample::<U2, _>(&mut rand::rng());
+    ///
+    /// assert!(pool.contains(&picked[0]) && pool.contains(&picked[1]));
+    /// assert_ne!(picked[0], picked[1]);
+    /// ```
+    pub fn sample<M, R>(&self, rng: &mut R) -> GenericArray<T, M>
+    where
+        T: Clone,
+        M: ArrayLength + IsLessOrEqual<N, Output = True>,
+        R: Rng + ?Sized,
+    {
+        // partial Fisher-Yates shuffle of the indices
+        let mut indices = GenericArray::<usize, N>::generate(|i| i);
+
+        for i in 0..M::USIZE {
+            let j = rng.random_range(i..N::USIZE);
+            indices.swap(i, j);
+        }
+
+        GenericArray::generate(|i| self[indices[i]].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_shuffle_distribution() {
+        const RUNS: usize = 6000;
+
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut counts = [[0usize; 3]; 3];
+
+        for _ in 0..RUNS {
+            let a = arr![0usize, 1, 2].shuffled(&mut rng);
+
+            for (pos, &x) in a.iter().enumerate() {
+                counts[pos][x] += 1;
+            }
+        }
+
+        // each element should land in each position roughly a third of the time
+        for count in counts.iter().flatten() {
+            assert!((1800..2200).contains(count), "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        use typenum::{U0, U2, U5};
+
+        const RUNS: usize = 5000;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let pool = arr![0usize, 1, 2, 3, 4];
+        let mut counts = [0usize; 5];
+
+        for _ in 0..RUNS {
+            let picked = pool.sample::<U2, _>(&mut rng);
+
+            assert_ne!(picked[0], picked[1]);
+
+            for &x in picked.iter() {
+                counts[x] += 1;
+            }
+        }
+
+        // each element should be chosen in roughly 2 out of 5 runs
+        for &count in counts.iter() {
+            assert!((1800..2200).contains(&count), "{:?}", counts);
+        }
+
+        let mut all = pool.sample::<U5, _>(&mut rng);
+        all.sort();
+        assert_eq!(all, pool);
+
+        assert!(pool.sample::<U0, _>(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_shuffled_move_only() {
+        extern crate alloc;
+
+        use alloc::boxed::Box;
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut a = arr![Box::new(1), Box::new(2), Box::new(3), Box::new(4)].shuffled(&mut rng);
+        a.sort();
+
+        assert_eq!(a, arr![Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Parallel initialization using `rayon`
+
+extern crate std;
+
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder};
+
+/// Drops the initialized prefix of a slice if filling it panics.
+struct FillGuard<'a, T> {
+    slots: &'a mut [MaybeUninit<T>],
+    position: usize,
+}
+
+impl<T> Drop for FillGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.slots.get_unchecked_mut(..self.position)
+                as *mut [MaybeUninit<T>] as *mut [T]);
+        }
+    }
+}
+
+/// Initializes every slot with `f(offset + i)`, recursively splitting the work with `rayon::join`.
+///
+/// If this panics, no slots are left initialized.
+fn par_fill<T, F>(slots: &mut [MaybeUninit<T>], offset: usize, min_len: usize, f: &F)
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    if slots.len() <= min_len {
+        let mut guard = FillGuard { slots, position: 0 };
+
+        while guard.position < guard.slots.len() {
+            let value = f(offset + guard.position);
+
+            unsafe { guard.slots.get_unchecked_mut(guard.position).write(value) };
+
+            guard.position += 1;
+        }
+
+        return mem::forget(guard);
+    }
+
+    let mid = slots.len() / 2;
+    let (left, right) = slots.split_at_mut(mid);
+
+    let (left_res, right_res) = rayon::join(
+        || {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                par_fill(&mut *left, offset, min_len, f)
+            }))
+        },
+        || {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                par_fill(&mut *right, offset + mid, min_len, f)
+            }))
+        },
+    );
+
+    // A half that panicked has already dropped its own elements,
+    // so only a half that completed needs to be dropped here.
+    match (left_res, right_res) {
+        (Ok(()), Ok(())) => {}
+        (Ok(()), Err(payload)) => {
+            drop(FillGuard {
+                slots: left,
+                position: mid,
+            });
+            panic::resume_unwind(payload);
+        }
+        (Err(payload), Ok(())) => {
+            drop(FillGuard {
+                position: right.len(),
+                slots: right,
+            });
+            panic::resume_unwind(payload);
+        }
+        (Err(payload), Err(_)) => panic::resume_unwind(payload),
+    }
+}
+
+impl<T: Send, N: ArrayLength> GenericArray<T, N> {
+    /// Initializes a new array in parallel using `rayon`, where element `i` is `f(i)`.
+    ///
+    /// This is the parallel equivalent of [`GenericSequence::generate`](crate::GenericSequence::generate),
+    /// useful when each element is expensive to compute.
+    ///
+    /// `f` is called exactly once for each index, but in no particular order and possibly
+    /// from multiple threads at once.
+    ///
+    /// If `f` panics, the panic is propagated once all running calls have finished,
+    /// and any already initialized elements will be dropped.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U4};
+    /// let squares = GenericArray::<u64, U4>::par_from_fn(|i| (i * i) as u64);
+    ///
+    /// assert_eq!(squares, arr![0, 1, 4, 9]);
+    /// ```
+    pub fn par_from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> T + Sync,
+    {
+        let min_len = N::USIZE.div_ceil(rayon::current_num_threads() * 4).max(1);
+
+        let mut array = GenericArray::uninit();
+
+        par_fill(array.as_mut_slice(), 0, min_len, &f);
+
+        unsafe { IntrusiveArrayBuilder::array_assume_init(array) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use super::std::panic;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_par_from_fn() {
+        let a = GenericArray::<usize, typenum::U1000>::par_from_fn(|i| i * 3);
+        let b = GenericArray::<usize, typenum::U1000>::generate(|i| i * 3);
+
+        assert_eq!(a, b);
+
+        let _ = GenericArray::<usize, typenum::U0>::par_from_fn(|i| i);
+    }
+
+    #[test]
+    fn test_par_from_fn_panic() {
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct TestDrop;
+
+        impl Drop for TestDrop {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let res = panic::catch_unwind(|| {
+            GenericArray::<TestDrop, typenum::U500>::par_from_fn(|i| {
+                if i % 97 == 96 {
+                    panic!();
+                }
+
+                CREATED.fetch_add(1, Ordering::SeqCst);
+                TestDrop
+            })
+        });
+
+        assert!(res.is_err());
+        assert!(CREATED.load(Ordering::SeqCst) > 0);
+        assert_eq!(
+            CREATED.load(Ordering::SeqCst),
+            DROPPED.load(Ordering::SeqCst)
+        );
+    }
+}
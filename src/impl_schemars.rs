@@ -0,0 +1,61 @@
+//! JSON schema implementation
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+
+use crate::{ArrayLength, GenericArray};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// `GenericArray<T, N>` is serialized the same as `[T; N]`,
+/// so the same schema name and id are used for both.
+impl<T: JsonSchema, N: ArrayLength> JsonSchema for GenericArray<T, N> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Array_size_{}_of_{}", N::USIZE, T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("[{}; {}]", T::schema_id(), N::USIZE).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<T>(),
+            "minItems": N::USIZE,
+            "maxItems": N::USIZE,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = schemars::schema_for!(GenericArray<u8, typenum::U3>);
+
+        assert_eq!(schema.get("type"), Some(&"array".into()));
+        assert_eq!(schema.get("minItems"), Some(&3.into()));
+        assert_eq!(schema.get("maxItems"), Some(&3.into()));
+        assert_eq!(
+            schema.get("items").and_then(|items| items.get("type")),
+            Some(&"integer".into())
+        );
+    }
+
+    #[test]
+    fn test_schema_matches_array() {
+        assert_eq!(
+            schemars::schema_for!(GenericArray<i32, typenum::U4>),
+            schemars::schema_for!([i32; 4])
+        );
+    }
+}
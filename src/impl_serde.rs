@@ -4,7 +4,7 @@ use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder};
 use core::fmt;
 use core::marker::PhantomData;
 
-use serde::de::{self, SeqAccess, Visitor};
+use serde::de::{self, DeserializeSeed, SeqAccess, Visitor};
 use serde::{ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
 
 impl<T, N: ArrayLength> Serialize for GenericArray<T, N>
@@ -110,6 +110,236 @@ where
     }
 }
 
+/// Deserializes a [`GenericArray`] by deserializing each element with a clone of the seed `S`.
+///
+/// This allows deserializing arrays of elements that require shared context,
+/// such as an interner, through the [`DeserializeSeed`] pattern.
+///
+/// ```rust
+/// use generic_array::{arr, GenericArray, GenericArraySeed, typenum::U3};
+/// use serde::de::{DeserializeSeed, Deserializer};
+/// use serde::Deserialize;
+///
+/// // adds an offset to each deserialized number
+/// #[derive(Clone, Copy)]
+/// struct Offset(u32);
+///
+/// impl<'de> DeserializeSeed<'de> for Offset {
+///     type Value = u32;
+///
+///     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<u32, D::Error> {
+///         Ok(u32::deserialize(deserializer)? + self.0)
+///     }
+/// }
+///
+/// let mut de = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// let array = GenericArraySeed::<_, U3>::new(Offset(10)).deserialize(&mut de).unwrap();
+///
+/// assert_eq!(array, arr![11, 12, 13]);
+/// ```
+pub struct GenericArraySeed<S, N> {
+    seed: S,
+    _n: PhantomData<N>,
+}
+
+impl<S, N: ArrayLength> GenericArraySeed<S, N> {
+    /// Creates a new seed that deserializes each of the `N` elements with a clone of `seed`.
+    #[inline]
+    pub const fn new(seed: S) -> Self {
+        GenericArraySeed {
+            seed,
+            _n: PhantomData,
+        }
+    }
+}
+
+impl<S: Clone, N> Clone for GenericArraySeed<S, N> {
+    fn clone(&self) -> Self {
+        GenericArraySeed {
+            seed: self.seed.clone(),
+            _n: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, N> Copy for GenericArraySeed<S, N> {}
+
+impl<S: fmt::Debug, N: ArrayLength> fmt::Debug for GenericArraySeed<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenericArraySeed")
+            .field("seed", &self.seed)
+            .field("len", &N::USIZE)
+            .finish()
+    }
+}
+
+impl<'de, S, N: ArrayLength> Visitor<'de> for GenericArraySeed<S, N>
+where
+    S: DeserializeSeed<'de> + Clone,
+{
+    type Value = GenericArray<S::Value, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "struct GenericArray<T, U{}>", N::USIZE)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match seq.size_hint() {
+            Some(n) if n != N::USIZE => {
+                return Err(de::Error::invalid_length(n, &self));
+            }
+            _ => {}
+        }
+
+        unsafe {
+            let mut dst = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut dst);
+
+            let (build_iter, position) = builder.iter_position();
+
+            for dst in build_iter {
+                match seq.next_element_seed(self.seed.clone())? {
+                    Some(el) => {
+                        dst.write(el);
+                        *position += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if *position == N::USIZE {
+                if seq.size_hint() != Some(0) && seq.next_element::<Dummy>()?.is_some() {
+                    return Err(de::Error::invalid_length(*position + 1, &self));
+                }
+
+                return Ok({
+                    builder.finish();
+                    IntrusiveArrayBuilder::array_assume_init(dst)
+                });
+            }
+
+            Err(de::Error::invalid_length(*position, &self))
+        }
+    }
+}
+
+impl<'de, S, N: ArrayLength> DeserializeSeed<'de> for GenericArraySeed<S, N>
+where
+    S: DeserializeSeed<'de> + Clone,
+{
+    type Value = GenericArray<S::Value, N>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(N::USIZE, self)
+    }
+}
+
+struct LengthCheckedVisitor<T, N> {
+    _t: PhantomData<T>,
+    _n: PhantomData<N>,
+}
+
+impl<'de, T, N: ArrayLength> Visitor<'de> for LengthCheckedVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = GenericArray<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of {} elements", N::USIZE)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<GenericArray<T, N>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let length_error =
+            |n| de::Error::custom(format_args!("expected {} elements, got {}", N::USIZE, n));
+
+        // fail before deserializing any elements if the declared length is known
+        match seq.size_hint() {
+            Some(n) if n != N::USIZE => return Err(length_error(n)),
+            _ => {}
+        }
+
+        unsafe {
+            let mut dst = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut dst);
+
+            {
+                let (build_iter, position) = builder.iter_position();
+
+                for dst in build_iter {
+                    match seq.next_element()? {
+                        Some(el) => {
+                            dst.write(el);
+                            *position += 1;
+                        }
+                        None => return Err(length_error(*position)),
+                    }
+                }
+            }
+
+            // count any extra elements for the error message
+            let mut len = N::USIZE;
+            while seq.next_element::<de::IgnoredAny>()?.is_some() {
+                len += 1;
+            }
+
+            if len != N::USIZE {
+                return Err(length_error(len));
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(dst))
+        }
+    }
+}
+
+/// Deserializes a [`GenericArray`] from a sequence whose length must be exactly `N`,
+/// for use with `#[serde(deserialize_with = "...")]`.
+///
+/// Unlike the [`Deserialize`] implementation for `GenericArray`, which deserializes a fixed-size
+/// tuple, this deserializes a sequence, so it reads the length prefix of formats that store one.
+/// That declared length is checked before any elements are deserialized, and mismatches are
+/// reported as `"expected N elements, got M"`.
+///
+/// ```rust
+/// use generic_array::{GenericArray, typenum::U4};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Key {
+///     #[serde(deserialize_with = "generic_array::deserialize_length_checked")]
+///     bytes: GenericArray<u8, U4>,
+/// }
+///
+/// let key: Key = serde_json::from_str(r#"{ "bytes": [1, 2, 3, 4] }"#).unwrap();
+/// assert_eq!(key.bytes[3], 4);
+///
+/// let err = serde_json::from_str::<Key>(r#"{ "bytes": [1, 2] }"#).err().unwrap();
+/// assert!(err.to_string().starts_with("expected 4 elements, got 2"));
+/// ```
+pub fn deserialize_length_checked<'de, D, T, N>(
+    deserializer: D,
+) -> Result<GenericArray<T, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    N: ArrayLength,
+{
+    deserializer.deserialize_seq(LengthCheckedVisitor {
+        _t: PhantomData,
+        _n: PhantomData,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +377,91 @@ mod tests {
         let serialized = "[1, 2, 3, 4, 5]";
         let _ = serde_json::from_str::<GenericArray<u8, typenum::U4>>(serialized).unwrap();
     }
+
+    #[derive(Clone, Copy)]
+    struct Counting<'a>(&'a core::cell::Cell<usize>);
+
+    impl<'de> DeserializeSeed<'de> for Counting<'_> {
+        type Value = (usize, u8);
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let value = u8::deserialize(deserializer)?;
+            let index = self.0.get();
+            self.0.set(index + 1);
+            Ok((index, value))
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let count = core::cell::Cell::new(0);
+        let seed = GenericArraySeed::<_, typenum::U3>::new(Counting(&count));
+
+        let mut de = serde_json::Deserializer::from_str("[7, 8, 9]");
+        let array = seed.deserialize(&mut de).unwrap();
+        assert_eq!(array, crate::arr![(0, 7), (1, 8), (2, 9)]);
+        assert_eq!(count.get(), 3);
+
+        let serialized =
+            bincode::Options::serialize(bincode::options(), &crate::arr![4u8, 5, 6]).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&serialized, bincode::options());
+        let array = seed.deserialize(&mut de).unwrap();
+        assert_eq!(array, crate::arr![(3, 4), (4, 5), (5, 6)]);
+        assert_eq!(count.get(), 6);
+
+        let mut de = serde_json::Deserializer::from_str("[1, 2]");
+        assert!(seed.deserialize(&mut de).is_err());
+
+        let mut de = serde_json::Deserializer::from_str("[1, 2, 3, 4]");
+        assert!(seed.deserialize(&mut de).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_length_checked() {
+        extern crate std;
+        use std::string::{String, ToString};
+
+        fn from_bincode<N: ArrayLength>(bytes: &[u8]) -> Result<GenericArray<u16, N>, String> {
+            let mut de = bincode::Deserializer::from_slice(bytes, bincode::options());
+            deserialize_length_checked(&mut de).map_err(|e| e.to_string())
+        }
+
+        fn from_json<N: ArrayLength>(json: &str) -> Result<GenericArray<u16, N>, String> {
+            let mut de = serde_json::Deserializer::from_str(json);
+            deserialize_length_checked(&mut de).map_err(|e| e.to_string())
+        }
+
+        // serializing a slice writes its length as a prefix
+        let prefixed = |len: u16| {
+            let values: std::vec::Vec<u16> = (0..len).collect();
+            bincode::Options::serialize(bincode::options(), values.as_slice()).unwrap()
+        };
+
+        assert_eq!(
+            from_bincode::<typenum::U4>(&prefixed(4)),
+            Ok(crate::arr![0, 1, 2, 3])
+        );
+        assert_eq!(
+            from_bincode::<typenum::U32>(&prefixed(16)),
+            Err("expected 32 elements, got 16".into())
+        );
+        assert_eq!(
+            from_bincode::<typenum::U4>(&prefixed(5)),
+            Err("expected 4 elements, got 5".into())
+        );
+
+        assert_eq!(
+            from_json::<typenum::U3>("[1, 2, 3]"),
+            Ok(crate::arr![1, 2, 3])
+        );
+
+        let short = from_json::<typenum::U3>("[1, 2]").unwrap_err();
+        assert!(short.starts_with("expected 3 elements, got 2"), "{}", short);
+
+        let long = from_json::<typenum::U3>("[1, 2, 3, 4, 5]").unwrap_err();
+        assert!(long.starts_with("expected 3 elements, got 5"), "{}", long);
+    }
 }
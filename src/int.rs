@@ -0,0 +1,179 @@
+//! Operations specific to arrays of primitive integers
+//!
+//! Arrays of integers can be converted to and from their byte representations, e.g.:
+//!
+//! ```
+//! # use generic_array::{arr, GenericArray, typenum::U2};
+//! let a = arr![0x0102u16, 0x0304];
+//!
+//! assert_eq!(a.to_le_bytes(), arr![2, 1, 4, 3]);
+//! assert_eq!(GenericArray::<u16, U2>::from_be_bytes(&arr![1, 2, 3, 4]), a);
+//! ```
+//!
+//! or have the byte order of each element swapped:
+//!
+//! ```
+//! # use generic_array::arr;
+//! assert_eq!(arr![0x0102u16, 0x0304].swap_bytes(), arr![0x0201, 0x0403]);
+//! ```
+
+use core::ops::Mul;
+
+use typenum::consts::{U1, U16, U2, U4, U8};
+use typenum::operator_aliases::Prod;
+
+use crate::functional::FunctionalSequence;
+use crate::sequence::GenericSequence;
+use crate::{ArrayLength, GenericArray};
+
+macro_rules! impl_int_bytes {
+    ($($t:ty => $w:ty),*) => {$(
+        impl<N: ArrayLength> GenericArray<$t, N>
+        where
+            N: Mul<$w>,
+            Prod<N, $w>: ArrayLength,
+        {
+            impl_int_bytes!(@to $t, $w, to_le_bytes, "little-endian");
+            impl_int_bytes!(@to $t, $w, to_be_bytes, "big-endian");
+            impl_int_bytes!(@to $t, $w, to_ne_bytes, "native-endian");
+
+            impl_int_bytes!(@from $t, $w, from_le_bytes, "little-endian");
+            impl_int_bytes!(@from $t, $w, from_be_bytes, "big-endian");
+            impl_int_bytes!(@from $t, $w, from_ne_bytes, "native-endian");
+        }
+    )*};
+
+    (@to $t:ty, $w:ty, $name:ident, $order:literal) => {
+        #[doc = concat!("Converts each element to its ", $order, " byte representation, concatenated in order.")]
+        ///
+        #[doc = concat!("See [`", stringify!($t), "::", stringify!($name), "`].")]
+        #[inline]
+        pub fn $name(&self) -> GenericArray<u8, Prod<N, $w>> {
+            let mut bytes = GenericArray::<u8, Prod<N, $w>>::default();
+
+            for (chunk, x) in GenericArray::<u8, $w>::chunks_from_slice_mut(&mut bytes)
+                .0
+                .iter_mut()
+                .zip(self.iter())
+            {
+                chunk.copy_from_slice(&x.$name());
+            }
+
+            bytes
+        }
+    };
+
+    (@from $t:ty, $w:ty, $name:ident, $order:literal) => {
+        #[doc = concat!("Creates an array from consecutive ", $order, " byte representations of each element.")]
+        ///
+        #[doc = concat!("See [`", stringify!($t), "::", stringify!($name), "`].")]
+        #[inline]
+        pub fn $name(bytes: &GenericArray<u8, Prod<N, $w>>) -> Self {
+            let chunks = GenericArray::<u8, $w>::chunks_from_slice(bytes).0;
+
+            GenericArray::generate(|i| <$t>::$name(chunks[i].into_array()))
+        }
+    };
+}
+
+impl_int_bytes! {
+    u8 => U1, u16 => U2, u32 => U4, u64 => U8, u128 => U16,
+    i8 => U1, i16 => U2, i32 => U4, i64 => U8, i128 => U16
+}
+
+macro_rules! impl_int_endian {
+    ($($t:ty),*) => {$(
+        impl<N: ArrayLength> GenericArray<$t, N> {
+            /// Reverses the byte order of each element, leaving the order of the elements unchanged.
+            ///
+            #[doc = concat!("See [`", stringify!($t), "::swap_bytes`].")]
+            #[inline]
+            pub fn swap_bytes(self) -> Self {
+                self.map(<$t>::swap_bytes)
+            }
+
+            /// Converts each element from the target's endianness to big-endian.
+            ///
+            /// On big-endian targets this is a no-op, otherwise the bytes of each element are swapped.
+            ///
+            #[doc = concat!("See [`", stringify!($t), "::to_be`].")]
+            #[inline]
+            pub fn to_be(self) -> Self {
+                self.map(<$t>::to_be)
+            }
+
+            /// Converts each element from the target's endianness to little-endian.
+            ///
+            /// On little-endian targets this is a no-op, otherwise the bytes of each element are swapped.
+            ///
+            #[doc = concat!("See [`", stringify!($t), "::to_le`].")]
+            #[inline]
+            pub fn to_le(self) -> Self {
+                self.map(<$t>::to_le)
+            }
+        }
+    )*};
+}
+
+impl_int_endian!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_int_checked {
+    ($($t:ty),*) => {$(
+        impl<N: ArrayLength> GenericArray<$t, N> {
+            impl_int_checked!(@op $t, checked_add, "addition", "[1, 2]", "[3, 4]", "[4, 6]", "[1, MAX]", "[1, 1]");
+            impl_int_checked!(@op $t, checked_sub, "subtraction", "[3, 4]", "[1, 2]", "[2, 2]", "[3, MIN]", "[1, 1]");
+            impl_int_checked!(@op $t, checked_mul, "multiplication", "[2, 3]", "[4, 5]", "[8, 15]", "[1, MAX]", "[1, 2]");
+        }
+    )*};
+
+    (@op $t:ty, $name:ident, $op:literal, $a:literal, $b:literal, $c:literal, $x:literal, $y:literal) => {
+        #[doc = concat!("Performs element-wise checked ", $op, ", returning `None` if any element overflows.")]
+        ///
+        /// Elements are computed in order, stopping at the first overflow.
+        ///
+        #[doc = concat!("See [`", stringify!($t), "::", stringify!($name), "`].")]
+        ///
+        /// ```
+        /// # use generic_array::{arr, GenericArray, typenum::U2};
+        #[doc = concat!("const MAX: ", stringify!($t), " = ", stringify!($t), "::MAX;")]
+        #[doc = concat!("const MIN: ", stringify!($t), " = ", stringify!($t), "::MIN;")]
+        ///
+        #[doc = concat!("let a = GenericArray::<", stringify!($t), ", U2>::from(", $a, ");")]
+        #[doc = concat!("assert_eq!(a.", stringify!($name), "(arr!", $b, "), Some(arr!", $c, "));")]
+        ///
+        #[doc = concat!("let b = GenericArray::<", stringify!($t), ", U2>::from(", $x, ");")]
+        #[doc = concat!("assert_eq!(b.", stringify!($name), "(arr!", $y, "), None);")]
+        /// ```
+        #[inline]
+        pub fn $name(self, other: Self) -> Option<Self> {
+            GenericArray::try_generate(|i| self[i].$name(other[i]).ok_or(())).ok()
+        }
+    };
+}
+
+impl_int_checked!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_int_widening {
+    ($($t:ty => $w:ty),*) => {$(
+        impl<N: ArrayLength> GenericArray<$t, N> {
+            #[doc = concat!("Sums the elements of the array into a `", stringify!($w), "` accumulator, which cannot overflow")]
+            #[doc = concat!("for any array of fewer than `2^(", stringify!($w), "::BITS - ", stringify!($t), "::BITS)` elements.")]
+            ///
+            /// Summing with [`Iterator::sum`] in the element type overflows for only a few large elements,
+            /// panicking in debug builds and silently wrapping in release builds.
+            ///
+            /// ```
+            /// # use generic_array::arr;
+            #[doc = concat!("let a = arr![", stringify!($t), "::MAX, ", stringify!($t), "::MAX, 2];")]
+            ///
+            #[doc = concat!("assert_eq!(a.sum_widening(), 2 * ", stringify!($t), "::MAX as ", stringify!($w), " + 2);")]
+            /// ```
+            #[inline]
+            pub fn sum_widening(&self) -> $w {
+                self.iter().map(|&x| x as $w).sum()
+            }
+        }
+    )*};
+}
+
+impl_int_widening!(u8 => u64, u16 => u64, u32 => u64, u64 => u128);
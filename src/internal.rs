@@ -21,6 +21,7 @@ pub struct ArrayBuilder<T, N: ArrayLength> {
 
 impl<T, N: ArrayLength> ArrayBuilder<T, N> {
     /// Begin building an array
+    #[allow(clippy::new_without_default)]
     #[inline(always)]
     pub const fn new() -> ArrayBuilder<T, N> {
         ArrayBuilder {
@@ -34,6 +35,11 @@ impl<T, N: ArrayLength> ArrayBuilder<T, N> {
     ///
     /// This makes no attempt to continue where a previous `extend` leaves off. Therefore, it should
     /// only be used once per `ArrayBuilder`.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called after elements have already been written, or they will be
+    /// overwritten without being dropped.
     #[inline(always)]
     pub unsafe fn extend(&mut self, source: impl Iterator<Item = T>) {
         let (destination, position) = (self.array.iter_mut(), &mut self.position);
@@ -73,13 +79,22 @@ impl<T, N: ArrayLength> ArrayBuilder<T, N> {
     /// }
     /// # }
     /// ```
+    ///
+    /// # Safety
+    ///
+    /// The position must only be incremented once per element actually written, so that
+    /// exactly the written elements are dropped if the builder is dropped.
     #[inline(always)]
-    pub unsafe fn iter_position(&mut self) -> (slice::IterMut<MaybeUninit<T>>, &mut usize) {
+    pub unsafe fn iter_position(&mut self) -> (slice::IterMut<'_, MaybeUninit<T>>, &mut usize) {
         (self.array.iter_mut(), &mut self.position)
     }
 
     /// When done writing (assuming all elements have been written to),
     /// get the inner array.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must have been written.
     #[inline(always)]
     pub const unsafe fn assume_init(self) -> GenericArray<T, N> {
         debug_assert!(self.is_full());
@@ -123,6 +138,11 @@ impl<'a, T, N: ArrayLength> IntrusiveArrayBuilder<'a, T, N> {
     ///
     /// This makes no attempt to continue where a previous `extend` leaves off. Therefore, it should
     /// only be used once per `ArrayBuilder`.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called after elements have already been written, or they will be
+    /// overwritten without being dropped.
     #[inline(always)]
     pub unsafe fn extend(&mut self, source: impl Iterator<Item = T>) {
         let (destination, position) = (self.array.iter_mut(), &mut self.position);
@@ -163,13 +183,22 @@ impl<'a, T, N: ArrayLength> IntrusiveArrayBuilder<'a, T, N> {
     /// }
     /// # }
     /// ```
+    ///
+    /// # Safety
+    ///
+    /// The position must only be incremented once per element actually written, so that
+    /// exactly the written elements are dropped if the builder is dropped.
     #[inline(always)]
-    pub unsafe fn iter_position(&mut self) -> (slice::IterMut<MaybeUninit<T>>, &mut usize) {
+    pub unsafe fn iter_position(&mut self) -> (slice::IterMut<'_, MaybeUninit<T>>, &mut usize) {
         (self.array.iter_mut(), &mut self.position)
     }
 
     /// When done writing (assuming all elements have been written to),
     /// get the inner array.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must have been written.
     #[inline(always)]
     pub const unsafe fn finish(self) {
         debug_assert!(self.is_full());
@@ -177,6 +206,10 @@ impl<'a, T, N: ArrayLength> IntrusiveArrayBuilder<'a, T, N> {
     }
 
     /// Similar to [`GenericArray::assume_init`] but not `const` and optimizes better.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must be initialized.
     #[inline(always)]
     pub unsafe fn array_assume_init(array: GenericArray<MaybeUninit<T>, N>) -> GenericArray<T, N> {
         ptr::read(&array as *const _ as *const MaybeUninit<GenericArray<T, N>>).assume_init()
@@ -218,8 +251,13 @@ impl<T, N: ArrayLength> ArrayConsumer<T, N> {
     /// to keep track of consumed elements.
     ///
     /// You MUST increment the position as you iterate to mark off consumed elements.
+    ///
+    /// # Safety
+    ///
+    /// The position must only be incremented once per element actually moved out,
+    /// so that exactly the remaining elements are dropped if the consumer is dropped.
     #[inline(always)]
-    pub unsafe fn iter_position(&mut self) -> (slice::Iter<T>, &mut usize) {
+    pub unsafe fn iter_position(&mut self) -> (slice::Iter<'_, T>, &mut usize) {
         (self.array.iter(), &mut self.position)
     }
 }
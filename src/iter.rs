@@ -1,9 +1,9 @@
 //! `GenericArray` iterator implementation.
 
 use super::{ArrayLength, GenericArray};
-use core::iter::FusedIterator;
+use core::iter::{Enumerate, FusedIterator, Rev};
 use core::mem::ManuallyDrop;
-use core::{cmp, fmt, mem, ptr};
+use core::{cmp, fmt, mem, ptr, slice};
 
 /// An iterator that moves out of a [`GenericArray`]
 pub struct GenericArrayIter<T, N: ArrayLength> {
@@ -45,6 +45,131 @@ impl<T, N: ArrayLength> IntoIterator for GenericArray<T, N> {
     }
 }
 
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Creates a consuming iterator that yields elements by value from last to first.
+    ///
+    /// This is equivalent to `self.into_iter().rev()`. If the iterator is dropped before
+    /// it is exhausted, the remaining elements are dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut iter = arr![1, 2, 3].into_iter_rev();
+    ///
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn into_iter_rev(self) -> Rev<GenericArrayIter<T, N>> {
+        self.into_iter().rev()
+    }
+
+    /// Creates a consuming iterator that yields elements by value along with their index
+    /// in the array.
+    ///
+    /// This is equivalent to `self.into_iter().enumerate()`. If the iterator is dropped before
+    /// it is exhausted, the remaining elements are dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut iter = arr!['a', 'b', 'c'].into_indexed_iter();
+    ///
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.next_back(), Some((2, 'c')));
+    /// assert_eq!(iter.next(), Some((1, 'b')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn into_indexed_iter(self) -> Enumerate<GenericArrayIter<T, N>> {
+        self.into_iter().enumerate()
+    }
+
+    /// Creates an iterator that yields mutable references to the elements from last to first.
+    ///
+    /// This is equivalent to `self.iter_mut().rev()`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3];
+    ///
+    /// // each element becomes the running total of itself and the elements after it
+    /// let mut total = 0;
+    /// for x in a.iter_mut_rev() {
+    ///     total += *x;
+    ///     *x = total;
+    /// }
+    ///
+    /// assert_eq!(a, arr![6, 5, 3]);
+    /// ```
+    #[inline]
+    pub fn iter_mut_rev(&mut self) -> Rev<slice::IterMut<'_, T>> {
+        self.iter_mut().rev()
+    }
+
+    /// Creates an iterator that yields mutable references to the elements from last to first,
+    /// along with their index in the array.
+    ///
+    /// Indices descend from `N - 1` to `0`, so each yielded index is the true position of its element.
+    /// This is equivalent to `self.iter_mut().enumerate().rev()`, whereas the similar-looking
+    /// `self.iter_mut().rev().enumerate()` pairs the last element with index `0`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![0; 3];
+    /// let mut iter = a.rindexed_mut();
+    ///
+    /// let (i, x) = iter.next().unwrap();
+    /// assert_eq!(i, 2);
+    /// *x = 20;
+    ///
+    /// assert_eq!(iter.next().map(|(i, _)| i), Some(1));
+    /// assert_eq!(iter.next().map(|(i, _)| i), Some(0));
+    /// assert!(iter.next().is_none());
+    ///
+    /// assert_eq!(a, arr![0, 0, 20]);
+    /// ```
+    #[inline]
+    pub fn rindexed_mut(&mut self) -> Rev<Enumerate<slice::IterMut<'_, T>>> {
+        self.iter_mut().enumerate().rev()
+    }
+
+    /// Iterates over each element along with its immediate left and right neighbors,
+    /// yielding `(prev, current, next)`.
+    ///
+    /// Exactly `N` items are yielded. The first item has no left neighbor and the last
+    /// item has no right neighbor, so those sides are `None`. For a single-element array,
+    /// both sides are `None`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3];
+    /// let mut iter = a.iter_neighbors();
+    ///
+    /// assert_eq!(iter.next(), Some((None, &1, Some(&2))));
+    /// assert_eq!(iter.next(), Some((Some(&1), &2, Some(&3))));
+    /// assert_eq!(iter.next(), Some((Some(&2), &3, None)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn iter_neighbors(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Option<&T>, &T, Option<&T>)> + ExactSizeIterator + '_
+    {
+        let slice = self.as_slice();
+
+        slice.iter().enumerate().map(move |(i, current)| {
+            let prev = match i {
+                0 => None,
+                _ => slice.get(i - 1),
+            };
+
+            (prev, current, slice.get(i + 1))
+        })
+    }
+}
+
 // Based on work in rust-lang/rust#49000
 impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for GenericArrayIter<T, N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -84,7 +84,13 @@
 //!     "zeroize",       # Zeroize implementation for setting array elements to zero
 //!     "const-default", # Compile-time const default value support via trait
 //!     "alloc",         # Enables From/TryFrom implementations between GenericArray and Vec<T>/Box<[T]>
-//!     "faster-hex"     # Enables internal use of the `faster-hex` crate for faster hex encoding via SIMD
+//!     "faster-hex",    # Enables internal use of the `faster-hex` crate for faster hex encoding via SIMD
+//!     "schemars",      # JsonSchema implementation matching the serde representation
+//!     "bytemuck",      # Byte-level views of arrays of `bytemuck::Pod` elements
+//!     "rayon",         # Parallel array initialization using `rayon` (requires `std`)
+//!     "rand",          # Random shuffling and sampling of arrays using `rand`
+//!     "num-traits",    # Vector and matrix operations on arrays of numbers, using `num-traits`
+//!     "base64",        # Fixed-length base64 encoding and decoding of byte arrays
 //! ]
 //! ```
 
@@ -99,16 +105,42 @@ pub extern crate typenum;
 #[cfg(feature = "alloc")]
 pub extern crate alloc;
 
+mod bytes;
+mod chunks;
+mod float;
 mod hex;
 mod impls;
+mod int;
 mod iter;
+mod ops;
+mod parse;
+mod search;
+mod sort;
+
+#[cfg(feature = "base64")]
+mod base64;
 
 #[cfg(feature = "alloc")]
 mod impl_alloc;
 
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck;
+
 #[cfg(feature = "const-default")]
 mod impl_const_default;
 
+#[cfg(feature = "num-traits")]
+mod impl_num_traits;
+
+#[cfg(feature = "rand")]
+mod impl_rand;
+
+#[cfg(feature = "rayon")]
+mod impl_rayon;
+
+#[cfg(feature = "schemars")]
+mod impl_schemars;
+
 #[cfg(feature = "serde")]
 mod impl_serde;
 
@@ -118,10 +150,11 @@ mod impl_zeroize;
 use core::iter::FromIterator;
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit};
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Mul};
 use core::{mem, ptr, slice};
 use typenum::bit::{B0, B1};
 use typenum::generic_const_mappings::{Const, ToUInt};
+use typenum::operator_aliases::Prod;
 use typenum::uint::{UInt, UTerm, Unsigned};
 
 #[doc(hidden)]
@@ -150,7 +183,14 @@ pub mod internals {
 use self::functional::*;
 use self::sequence::*;
 
+#[cfg(feature = "base64")]
+pub use self::base64::{Base64Error, Base64Len};
+pub use self::chunks::ChunksMut;
+pub use self::hex::{HexDebug, HexError};
+#[cfg(feature = "serde")]
+pub use self::impl_serde::{deserialize_length_checked, GenericArraySeed};
 pub use self::iter::GenericArrayIter;
+pub use self::parse::ParseError;
 
 /// `ArrayLength` is a type-level [`Unsigned`] integer used to
 /// define the number of elements in a [`GenericArray`].
@@ -527,6 +567,29 @@ where
         }
     }
 
+    #[inline(always)]
+    fn try_generate<E, F>(mut f: F) -> Result<GenericArray<T, N>, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        unsafe {
+            let mut array = GenericArray::<T, N>::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (builder_iter, position) = builder.iter_position();
+
+                for (i, dst) in builder_iter.enumerate() {
+                    dst.write(f(i)?);
+                    *position += 1;
+                }
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+
     #[inline(always)]
     fn inverted_zip<B, U, F>(
         self,
@@ -668,6 +731,20 @@ where
 }
 
 impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// The number of elements in the array.
+    ///
+    /// Unlike [`len`](GenericArray::len), this can be used in constant expressions
+    /// such as the length of native arrays, as long as `N` is a concrete type.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::U4};
+    /// type Block = GenericArray<u8, U4>;
+    ///
+    /// let buffer = [0u8; Block::LEN * 2];
+    /// assert_eq!(buffer.len(), 8);
+    /// ```
+    pub const LEN: usize = N::USIZE;
+
     /// Returns the number of elements in the array.
     ///
     /// Equivalent to [`<N as Unsigned>::USIZE`](typenum::Unsigned) where `N` is the array length.
@@ -817,6 +894,37 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
         unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len() * N::USIZE) }
     }
 
+    /// Creates an array with every element set to a copy of `value`.
+    ///
+    /// Unlike [`GenericSequence::generate`] or [`Default`], this is a `const fn`,
+    /// so it can be used to initialize `const` and `static` items. This requires Rust 1.83,
+    /// the minimum supported version of this crate.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::U32};
+    /// static ONES: GenericArray<u8, U32> = GenericArray::from_element(1);
+    ///
+    /// assert!(ONES.iter().all(|&x| x == 1));
+    /// ```
+    #[inline]
+    pub const fn from_element(value: T) -> Self
+    where
+        T: Copy,
+    {
+        let mut array = GenericArray::<T, N>::uninit();
+        let ptr = &mut array as *mut GenericArray<MaybeUninit<T>, N> as *mut MaybeUninit<T>;
+
+        let mut i = 0;
+        while i < N::USIZE {
+            // SAFETY: `i < N`, so the write is within the array
+            unsafe { ptr.add(i).write(MaybeUninit::new(value)) };
+            i += 1;
+        }
+
+        // SAFETY: every element was initialized above
+        unsafe { GenericArray::assume_init(array) }
+    }
+
     /// Convert a native array into `GenericArray` of the same length and type.
     ///
     /// This is the `const` equivalent of using the standard [`From`]/[`Into`] traits methods.
@@ -839,6 +947,65 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
         unsafe { crate::const_transmute(self) }
     }
 
+    /// Convert a native array into `GenericArray` of the same length and type,
+    /// checking the lengths at runtime.
+    ///
+    /// Unlike [`from_array`](GenericArray::from_array), this does not require a compile-time
+    /// proof that `M` corresponds to `N`, which is useful in generic code mixing const generics and
+    /// typenum lengths where that bound cannot be expressed.
+    ///
+    /// ```rust
+    /// # use generic_array::{arr, GenericArray, typenum::U3};
+    /// let a = GenericArray::<i32, U3>::from_const_array([1, 2, 3]);
+    ///
+    /// assert_eq!(a, arr![1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message naming both lengths if `M` is not equal to `N`.
+    #[inline]
+    #[track_caller]
+    pub fn from_const_array<const M: usize>(value: [T; M]) -> Self {
+        assert!(
+            M == N::USIZE,
+            "cannot convert [T; {}] into GenericArray<T, N> with N = {}",
+            M,
+            N::USIZE
+        );
+
+        unsafe { crate::const_transmute(value) }
+    }
+
+    /// Convert the `GenericArray` into a native array of the same length and type,
+    /// checking the lengths at runtime.
+    ///
+    /// Unlike [`into_array`](GenericArray::into_array), this does not require a compile-time
+    /// proof that `M` corresponds to `N`.
+    ///
+    /// ```rust
+    /// # use generic_array::arr;
+    /// let a: [i32; 3] = arr![1, 2, 3].into_const_array();
+    ///
+    /// assert_eq!(a, [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message naming both lengths if `M` is not equal to `N`.
+    #[inline]
+    #[track_caller]
+    pub fn into_const_array<const M: usize>(self) -> [T; M] {
+        assert!(
+            M == N::USIZE,
+            "cannot convert GenericArray<T, N> with N = {} into [T; {}]",
+            N::USIZE,
+            M
+        );
+
+        unsafe { crate::const_transmute(self) }
+    }
+
     /// Convert a slice of native arrays into a slice of `GenericArray`s.
     #[inline(always)]
     pub const fn from_chunks<const U: usize>(chunks: &[[T; U]]) -> &[GenericArray<T, N>]
@@ -874,6 +1041,61 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     {
         unsafe { mem::transmute(chunks) }
     }
+
+    /// Converts the array to an array with a different length type `M` of the same value.
+    ///
+    /// This is useful when typenum arithmetic produces a length type that is equivalent to,
+    /// but not syntactically the same as, the one required, such as `Sum<U2, U3>` and `U5`
+    /// in generic code.
+    ///
+    /// ```rust
+    /// # use generic_array::{arr, GenericArray, typenum::{Sum, U2, U3, U5}};
+    /// fn takes_five(a: GenericArray<i32, U5>) -> i32 {
+    ///     a.iter().sum()
+    /// }
+    ///
+    /// let a: GenericArray<i32, Sum<U2, U3>> = arr![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(takes_five(a.relabel()), 15);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is not equal to `N`. When called in a const context,
+    /// this is a compile-time error instead.
+    #[inline(always)]
+    pub const fn relabel<M: ArrayLength>(self) -> GenericArray<T, M> {
+        if N::USIZE != M::USIZE {
+            panic!("N != M in GenericArray::relabel");
+        }
+
+        unsafe { const_transmute(self) }
+    }
+}
+
+impl<T, C: ArrayLength, R: ArrayLength> GenericArray<GenericArray<T, C>, R> {
+    /// Reinterprets an `R`x`C` array of arrays as an `R2`x`C2` array of arrays,
+    /// where `R * C == R2 * C2`.
+    ///
+    /// Nested arrays are laid out contiguously in row-major order, so the elements keep
+    /// their flattened order and are not moved, only regrouped into rows of `C2` elements.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::{U2, U3}};
+    /// let a = arr![arr![1, 2, 3], arr![4, 5, 6]];
+    /// let b: GenericArray<GenericArray<i32, U2>, U3> = a.reshape();
+    ///
+    /// assert_eq!(b, arr![arr![1, 2], arr![3, 4], arr![5, 6]]);
+    /// ```
+    #[inline(always)]
+    pub const fn reshape<R2, C2>(self) -> GenericArray<GenericArray<T, C2>, R2>
+    where
+        C: Mul<R>,
+        R2: ArrayLength,
+        C2: ArrayLength + Mul<R2, Output = Prod<C, R>>,
+    {
+        unsafe { const_transmute(self) }
+    }
 }
 
 impl<T, N: ArrayLength> GenericArray<T, N> {
@@ -916,6 +1138,44 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     pub const unsafe fn assume_init(array: GenericArray<MaybeUninit<T>, N>) -> Self {
         const_transmute::<_, MaybeUninit<GenericArray<T, N>>>(array).assume_init()
     }
+
+    /// Views an initialized array as an array of `MaybeUninit<T>` containers.
+    #[inline(always)]
+    pub const fn as_uninit(&self) -> &GenericArray<MaybeUninit<T>, N> {
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`
+        unsafe { &*(self as *const Self as *const GenericArray<MaybeUninit<T>, N>) }
+    }
+
+    /// Views an initialized array as a mutable array of `MaybeUninit<T>` containers.
+    ///
+    /// This is useful for overwriting elements in-place without dropping the previous values,
+    /// such as when hardware or DMA writes directly into the buffer.
+    ///
+    /// # Safety
+    ///
+    /// All elements must still be in an initialized state when the returned reference
+    /// is no longer used, as `self` is assumed to be initialized afterwards. Any value
+    /// overwritten through the returned reference is not dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut array = arr![1, 2, 3];
+    ///
+    /// // SAFETY: Every element is re-initialized before `array` is used again
+    /// unsafe {
+    ///     for (i, slot) in array.as_uninit_mut().iter_mut().enumerate() {
+    ///         slot.write(i as i32 * 10);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(array, arr![0, 10, 20]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn as_uninit_mut(&mut self) -> &mut GenericArray<MaybeUninit<T>, N> {
+        &mut *(self as *mut Self as *mut GenericArray<MaybeUninit<T>, N>)
+    }
 }
 
 /// Error for [`TryFrom`] and [`try_from_iter`](GenericArray::try_from_iter)
@@ -985,6 +1245,75 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
             })
         }
     }
+
+    /// Creates a new array from an iterator that is trusted to yield exactly `N` elements,
+    /// such as one whose [`ExactSizeIterator`] length is known to be `N`.
+    ///
+    /// This skips the size hint pre-checks of [`try_from_iter`](GenericArray::try_from_iter),
+    /// and the final check that the iterator has no more elements, which is only performed
+    /// in debug builds. In release builds, any elements beyond the first `N` are silently left
+    /// in the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields fewer than `N` elements. In debug builds,
+    /// also panics if it yields more than `N` elements.
+    #[cfg(feature = "internals")]
+    #[inline]
+    pub fn from_trusted_len_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        unsafe {
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            builder.extend(&mut iter);
+
+            if !builder.is_full() {
+                from_iter_length_fail(N::USIZE);
+            }
+
+            debug_assert!(
+                iter.next().is_none(),
+                "GenericArray::from_trusted_len_iter expected {} items",
+                N::USIZE
+            );
+
+            builder.finish();
+            IntrusiveArrayBuilder::array_assume_init(array)
+        }
+    }
+
+    /// Fallibly creates a new array by calling `f` with a mutable reference to `state`
+    /// and the index of each element, in order.
+    ///
+    /// On the first error, any already initialized elements are dropped and the error is returned.
+    /// The state is left as it was after the last call to `f`.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U3};
+    /// let input = [1u8, 2, 3, 4];
+    /// let mut cursor = &input[..];
+    ///
+    /// let read = |cursor: &mut &[u8], _| match cursor.split_first() {
+    ///     Some((&b, rest)) => { *cursor = rest; Ok(b) }
+    ///     None => Err("eof"),
+    /// };
+    ///
+    /// assert_eq!(GenericArray::<u8, U3>::try_from_fn_with(&mut cursor, read), Ok(arr![1, 2, 3]));
+    /// assert_eq!(GenericArray::<u8, U3>::try_from_fn_with(&mut cursor, read), Err("eof"));
+    /// assert!(cursor.is_empty());
+    /// ```
+    #[inline]
+    pub fn try_from_fn_with<S, E, F>(state: &mut S, mut f: F) -> Result<Self, E>
+    where
+        F: FnMut(&mut S, usize) -> Result<T, E>,
+    {
+        Self::try_generate(|i| f(state, i))
+    }
 }
 
 /// A const reimplementation of the [`transmute`](core::mem::transmute) function,
@@ -1017,6 +1346,9 @@ mod test {
     // and view the assembly to make sure test_assembly generates
     // SIMD instructions instead of a naive loop.
 
+    use crate::GenericArray;
+    use typenum::{U16, U32, U64};
+
     #[inline(never)]
     pub fn black_box<T>(val: T) -> T {
         use core::{mem, ptr};
@@ -1041,4 +1373,49 @@ mod test {
 
         assert_eq!(d, 16);
     }
+
+    // `xor_assign` should compile to whole-vector XORs (`xorps`/`pxor`) with no loop
+    #[inline(never)]
+    fn xor_assign_u16(a: &mut GenericArray<u8, U16>, b: &GenericArray<u8, U16>) {
+        a.xor_assign(b);
+    }
+
+    #[inline(never)]
+    fn xor_assign_u32(a: &mut GenericArray<u8, U32>, b: &GenericArray<u8, U32>) {
+        a.xor_assign(b);
+    }
+
+    #[test]
+    fn test_assembly_xor() {
+        use crate::sequence::GenericSequence;
+
+        let mut a = black_box(GenericArray::<u8, U32>::generate(|i| i as u8));
+        let b = black_box(GenericArray::<u8, U32>::generate(|_| 0xA5));
+
+        xor_assign_u32(&mut a, &b);
+        assert_eq!(a, GenericArray::generate(|i| i as u8 ^ 0xA5));
+
+        let mut c = black_box(GenericArray::<u8, U16>::generate(|i| i as u8 ^ 0xA5));
+        let d = black_box(GenericArray::<u8, U16>::generate(|_| 0xA5));
+
+        xor_assign_u16(&mut c, &d);
+        assert_eq!(c, GenericArray::generate(|i| i as u8));
+    }
+
+    // `count_byte` should compile to full-width byte compares (`pcmpeqb`) reduced with `psadbw`, with no loop
+    #[inline(never)]
+    fn count_byte_u64(a: &GenericArray<u8, U64>, needle: u8) -> usize {
+        a.count_byte(needle)
+    }
+
+    #[test]
+    fn test_assembly_count_byte() {
+        use crate::sequence::GenericSequence;
+
+        let a = black_box(GenericArray::<u8, U64>::generate(|i| (i % 10) as u8));
+
+        assert_eq!(count_byte_u64(&a, 3), 7);
+        assert_eq!(count_byte_u64(&a, 9), 6);
+        assert_eq!(count_byte_u64(&a, 10), 0);
+    }
 }
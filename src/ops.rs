@@ -0,0 +1,1247 @@
+//! Element-wise operations and transformations for `GenericArray`
+
+use core::cmp::Ordering;
+use core::iter::FromIterator;
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Add, Mul, RangeBounds, Sub};
+use core::ptr;
+
+use typenum::operator_aliases::{Add1, Diff, Sub1};
+use typenum::{IsLessOrEqual, NonZero, True, B1};
+
+use crate::functional::FunctionalSequence;
+use crate::{ArrayConsumer, ArrayLength, GenericArray, GenericSequence, IntrusiveArrayBuilder};
+
+/// Consumes an array starting at `mid`, wrapping around to the front.
+///
+/// Any elements not yet taken are dropped along with the consumer.
+struct RotatedConsumer<T, N: ArrayLength> {
+    array: ManuallyDrop<GenericArray<T, N>>,
+    mid: usize,
+    taken: usize,
+}
+
+impl<T, N: ArrayLength> RotatedConsumer<T, N> {
+    /// Moves out the next element in rotated order.
+    ///
+    /// Must not be called more than `N` times.
+    #[inline(always)]
+    unsafe fn take(&mut self) -> T {
+        let tail = N::USIZE - self.mid;
+
+        let idx = match self.taken < tail {
+            true => self.mid + self.taken,
+            false => self.taken - tail,
+        };
+
+        let value = ptr::read(self.array.get_unchecked(idx));
+
+        self.taken += 1;
+
+        value
+    }
+}
+
+impl<T, N: ArrayLength> Drop for RotatedConsumer<T, N> {
+    fn drop(&mut self) {
+        let tail = N::USIZE - self.mid;
+
+        unsafe {
+            if self.taken < tail {
+                ptr::drop_in_place(self.array.get_unchecked_mut(self.mid + self.taken..));
+                ptr::drop_in_place(self.array.get_unchecked_mut(..self.mid));
+            } else {
+                ptr::drop_in_place(self.array.get_unchecked_mut(self.taken - tail..self.mid));
+            }
+        }
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Combines two arrays element-wise, passing the index of each pair of elements
+    /// to the combining function along with the elements themselves.
+    ///
+    /// If the combining function panics, any already initialized elements in the new array
+    /// will be dropped, AND any unused elements in both source arrays will also be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3];
+    /// let b = arr![10, 20, 30];
+    ///
+    /// assert_eq!(a.zip_indexed(b, |i, x, y| i as i32 * x + y), arr![10, 22, 36]);
+    /// ```
+    #[inline]
+    pub fn zip_indexed<B, U, F>(self, rhs: GenericArray<B, N>, mut f: F) -> GenericArray<U, N>
+    where
+        F: FnMut(usize, T, B) -> U,
+    {
+        unsafe {
+            let mut left = ArrayConsumer::new(self);
+            let mut right = ArrayConsumer::new(rhs);
+
+            let (left_array_iter, left_position) = left.iter_position();
+            let (right_array_iter, right_position) = right.iter_position();
+
+            FromIterator::from_iter(left_array_iter.zip(right_array_iter).map(|(l, r)| {
+                let left_value = ptr::read(l);
+                let right_value = ptr::read(r);
+
+                let i = *left_position;
+
+                *left_position += 1;
+                *right_position = *left_position;
+
+                f(i, left_value, right_value)
+            }))
+        }
+    }
+
+    /// Combines two arrays element-wise with a fallible function, stopping at the first error.
+    ///
+    /// On error, any already initialized elements in the new array will be dropped,
+    /// AND any unused elements in both source arrays will also be dropped. The same
+    /// applies if the combining function panics.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1u8, 2, 3];
+    ///
+    /// assert_eq!(a.try_zip(arr![4, 5, 6], |x, y| x.checked_add(y).ok_or(())), Ok(arr![5, 7, 9]));
+    /// assert_eq!(a.try_zip(arr![4, 5, 255], |x, y| x.checked_add(y).ok_or(())), Err(()));
+    /// ```
+    #[inline]
+    pub fn try_zip<B, U, E, F>(
+        self,
+        rhs: GenericArray<B, N>,
+        mut f: F,
+    ) -> Result<GenericArray<U, N>, E>
+    where
+        F: FnMut(T, B) -> Result<U, E>,
+    {
+        unsafe {
+            let mut left = ArrayConsumer::new(self);
+            let mut right = ArrayConsumer::new(rhs);
+
+            let (left_array_iter, left_position) = left.iter_position();
+            let (right_array_iter, right_position) = right.iter_position();
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for ((dst, l), r) in dst_iter.zip(left_array_iter).zip(right_array_iter) {
+                    let left_value = ptr::read(l);
+                    let right_value = ptr::read(r);
+
+                    *left_position += 1;
+                    *right_position = *left_position;
+
+                    dst.write(f(left_value, right_value)?);
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+
+    /// Maps each element along with its index using a fallible function, stopping at the first error.
+    ///
+    /// On error, any already initialized elements in the new array will be dropped,
+    /// AND any unused elements in the source array will also be dropped. The same
+    /// applies if the mapping function panics.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// // the first field is hexadecimal, the rest are decimal
+    /// let parse = |i, s: &str| u8::from_str_radix(s, if i == 0 { 16 } else { 10 });
+    ///
+    /// assert_eq!(arr!["ff", "10", "20"].try_map_indexed(parse), Ok(arr![255, 10, 20]));
+    /// assert!(arr!["ff", "1f", "20"].try_map_indexed(parse).is_err());
+    /// ```
+    #[inline]
+    pub fn try_map_indexed<U, E, F>(self, mut f: F) -> Result<GenericArray<U, N>, E>
+    where
+        F: FnMut(usize, T) -> Result<U, E>,
+    {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+            let (src_iter, src_position) = source.iter_position();
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for (dst, src) in dst_iter.zip(src_iter) {
+                    let value = ptr::read(src);
+
+                    let i = *src_position;
+                    *src_position += 1;
+
+                    dst.write(f(i, value)?);
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+
+    /// Rotates the array to the left by `mid` elements and maps each element, in a single pass.
+    ///
+    /// Element `i` of the output is `f(self[(i + mid) % N])`, equivalent to
+    /// calling [`rotate_left(mid)`](slice::rotate_left) followed by [`map(f)`](crate::functional::FunctionalSequence::map).
+    /// Elements are passed to `f` in output order.
+    ///
+    /// If the mapping function panics, any already initialized elements in the new array
+    /// will be dropped, AND any unused elements in the source array will also be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(a.rotated_map(2, |x| x * 10), arr![30, 40, 50, 10, 20]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the array.
+    #[inline]
+    pub fn rotated_map<U, F>(self, mid: usize, mut f: F) -> GenericArray<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        assert!(mid <= N::USIZE, "mid > len in GenericArray::rotated_map");
+
+        let mut source = RotatedConsumer {
+            array: ManuallyDrop::new(self),
+            mid,
+            taken: 0,
+        };
+
+        GenericArray::generate(|_| f(unsafe { source.take() }))
+    }
+
+    /// Computes an inclusive scan of the array, where element `i` of the output
+    /// is the result of combining the output element `i - 1` with input element `i`.
+    ///
+    /// The first element is passed through unchanged.
+    ///
+    /// If the combining function panics, any already initialized elements in the new array
+    /// will be dropped, AND any unused elements in the source array will also be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![3, 1, 4, 1, 5];
+    ///
+    /// assert_eq!(a.prefix_scan_by(|acc, x| (*acc).max(x)), arr![3, 3, 4, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn prefix_scan_by<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(&T, T) -> T,
+    {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+            let (src_iter, src_position) = source.iter_position();
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                let mut prev: Option<&T> = None;
+
+                for (dst, src) in dst_iter.zip(src_iter) {
+                    let value = ptr::read(src);
+
+                    *src_position += 1;
+
+                    let value = match prev {
+                        Some(acc) => f(acc, value),
+                        None => value,
+                    };
+
+                    prev = Some(dst.write(value));
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            IntrusiveArrayBuilder::array_assume_init(array)
+        }
+    }
+
+    /// Computes the running totals of the array, where element `i` of the output
+    /// is the sum of input elements `0..=i`.
+    ///
+    /// Overflow behaves the same as repeatedly applying `+`, so integer overflow will
+    /// panic in debug builds and wrap in release builds.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![1, 2, 3, 4].prefix_sum(), arr![1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    pub fn prefix_sum(self) -> Self
+    where
+        T: Add<Output = T> + Clone,
+    {
+        self.prefix_scan_by(|acc, x| acc.clone() + x)
+    }
+
+    /// Writes the running totals of the array into `out`, where `out[i]` is the sum
+    /// of elements `0..=i` of `self`.
+    ///
+    /// This is the non-consuming equivalent of [`prefix_sum`](GenericArray::prefix_sum),
+    /// allowing an output buffer to be reused across calls. The borrow rules prevent `out`
+    /// from aliasing `self`; to accumulate a `Copy` array in place, use `a = a.prefix_sum()`.
+    ///
+    /// Overflow behaves the same as repeatedly applying `+`. If a panic occurs, `out` is left
+    /// with the totals written so far followed by its previous contents.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U4};
+    /// let mut out = GenericArray::<i32, U4>::default();
+    ///
+    /// arr![1, 2, 3, 4].prefix_sum_into(&mut out);
+    /// assert_eq!(out, arr![1, 3, 6, 10]);
+    ///
+    /// arr![5, 5, 5, 5].prefix_sum_into(&mut out);
+    /// assert_eq!(out, arr![5, 10, 15, 20]);
+    /// ```
+    #[inline]
+    pub fn prefix_sum_into(&self, out: &mut GenericArray<T, N>)
+    where
+        T: Add<Output = T> + Copy,
+    {
+        let mut acc: Option<T> = None;
+
+        for (dst, &x) in out.iter_mut().zip(self.iter()) {
+            let total = match acc {
+                Some(acc) => acc + x,
+                None => x,
+            };
+
+            *dst = total;
+            acc = Some(total);
+        }
+    }
+
+    /// Computes the differences between adjacent elements, where `out[i]` is `self[i + 1] - self[i]`.
+    ///
+    /// The result is one element shorter than `self`, so a single-element array gives an
+    /// empty array. Calling this on an empty array is a compile-time error.
+    ///
+    /// Overflow behaves the same as repeatedly applying `-`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 4, 9, 16, 25];
+    ///
+    /// assert_eq!(a.diff(), arr![3, 5, 7, 9]);
+    /// assert_eq!(a.diff().diff(), arr![2, 2, 2]);
+    /// ```
+    #[inline]
+    pub fn diff(&self) -> GenericArray<T, Sub1<N>>
+    where
+        T: Sub<Output = T> + Copy,
+        N: Sub<B1>,
+        Sub1<N>: ArrayLength,
+    {
+        GenericArray::generate(|i| self[i + 1] - self[i])
+    }
+
+    /// Maps each element into a pair of values, collecting the first and second
+    /// halves of each pair into two separate arrays in a single pass.
+    ///
+    /// This is the array equivalent of [`Iterator::unzip`].
+    ///
+    /// If the mapping function panics, any already initialized elements in both new arrays
+    /// will be dropped, AND any unused elements in the source array will also be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![7, 8, 9];
+    ///
+    /// let (quot, rem) = a.unzip_map(|x| (x / 4, x % 4));
+    ///
+    /// assert_eq!(quot, arr![1, 2, 2]);
+    /// assert_eq!(rem, arr![3, 0, 1]);
+    /// ```
+    #[inline]
+    pub fn unzip_map<A, B, F>(self, mut f: F) -> (GenericArray<A, N>, GenericArray<B, N>)
+    where
+        F: FnMut(T) -> (A, B),
+    {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+            let (src_iter, src_position) = source.iter_position();
+
+            let mut left = GenericArray::uninit();
+            let mut right = GenericArray::uninit();
+
+            let mut left_builder = IntrusiveArrayBuilder::new(&mut left);
+            let mut right_builder = IntrusiveArrayBuilder::new(&mut right);
+
+            {
+                let (left_iter, left_position) = left_builder.iter_position();
+                let (right_iter, right_position) = right_builder.iter_position();
+
+                for ((l, r), src) in left_iter.zip(right_iter).zip(src_iter) {
+                    let value = ptr::read(src);
+
+                    *src_position += 1;
+
+                    let (a, b) = f(value);
+
+                    l.write(a);
+                    r.write(b);
+
+                    *left_position += 1;
+                    *right_position = *left_position;
+                }
+            }
+
+            left_builder.finish();
+            right_builder.finish();
+
+            (
+                IntrusiveArrayBuilder::array_assume_init(left),
+                IntrusiveArrayBuilder::array_assume_init(right),
+            )
+        }
+    }
+
+    /// Replaces the element at index `i` with `value`, returning the old element.
+    ///
+    /// If `i` is out of bounds, the array is left untouched and the index is returned
+    /// along with `value`, so ownership of it is never lost.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3];
+    ///
+    /// assert_eq!(a.set(1, 20), Ok(2));
+    /// assert_eq!(a.set(3, 40), Err((3, 40)));
+    /// assert_eq!(a, arr![1, 20, 3]);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, i: usize, value: T) -> Result<T, (usize, T)> {
+        match self.get_mut(i) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err((i, value)),
+        }
+    }
+
+    /// Consumes the array, returning it with the element at index `i` replaced by `value`.
+    ///
+    /// The old element is dropped, and all other elements are moved without being cloned.
+    /// This is useful for functional-style updates in a chain of calls.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3].with(0, 10).with(2, 30);
+    ///
+    /// assert_eq!(a, arr![10, 2, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn with(mut self, i: usize, value: T) -> Self {
+        self[i] = value;
+        self
+    }
+
+    /// Copies the elements in the range `src` to the position starting at `dest`,
+    /// within the same array.
+    ///
+    /// The source and destination may overlap. This is equivalent to
+    /// [`slice::copy_within`] on the array's slice.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4, 5];
+    ///
+    /// a.copy_within(1..3, 2);
+    ///
+    /// assert_eq!(a, arr![1, 2, 2, 3, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range exceeds the end of the array, or if the end of `src`
+    /// is before its start.
+    #[inline]
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        self.as_mut_slice().copy_within(src, dest);
+    }
+
+    /// Reduces the array to a single element by repeatedly applying `f`, from left to right.
+    ///
+    /// The first element is used as the initial accumulator, so `None` is only returned
+    /// for zero-length arrays. This is the array equivalent of [`Iterator::reduce`].
+    ///
+    /// If the combining function panics, any unused elements in the source array will be dropped.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::U0};
+    /// assert_eq!(arr![1, 2, 3, 4].reduce(|a, b| a * 10 + b), Some(1234));
+    /// assert_eq!(GenericArray::<i32, U0>::default().reduce(|a, b| a + b), None);
+    /// ```
+    #[inline]
+    pub fn reduce<F>(self, mut f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+
+            let (mut array_iter, position) = source.iter_position();
+
+            let first = ptr::read(array_iter.next()?);
+            *position += 1;
+
+            Some(array_iter.fold(first, |acc, src| {
+                let value = ptr::read(src);
+                *position += 1;
+                f(acc, value)
+            }))
+        }
+    }
+
+    /// Reduces a non-empty array to a single element by repeatedly applying `f`, from left to right.
+    ///
+    /// This is the infallible equivalent of [`reduce`](GenericArray::reduce)
+    /// for lengths marked [`NonZero`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![3, 9, 2].reduce_nonempty(i32::max), 9);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty, which is only possible with a non-canonical
+    /// length such as `UInt<UTerm, B0>` that `typenum` still considers [`NonZero`].
+    #[track_caller]
+    #[inline]
+    pub fn reduce_nonempty<F>(self, f: F) -> T
+    where
+        N: NonZero,
+        F: FnMut(T, T) -> T,
+    {
+        self.reduce(f).expect("array is non-empty")
+    }
+
+    /// Restricts each element to the interval `[min, max]`.
+    ///
+    /// See [`Ord::clamp`] for details.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![-5, 0, 5, 300].clamp_each(0, 255), arr![0, 0, 5, 255]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[inline]
+    pub fn clamp_each(self, min: T, max: T) -> Self
+    where
+        T: Ord + Copy,
+    {
+        assert!(min <= max, "min > max in GenericArray::clamp_each");
+
+        self.map(|x| x.clamp(min, max))
+    }
+
+    /// Restricts each element to the interval `[min, max]` with respect to the comparator function.
+    ///
+    /// This allows clamping types which are not [`Ord`], such as floats:
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![-0.5f32, 0.25, 1.5];
+    ///
+    /// assert_eq!(a.clamp_each_by(0.0, 1.0, |x, y| x.total_cmp(y)), arr![0.0, 0.25, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` compares greater than `max`.
+    #[inline]
+    pub fn clamp_each_by<F>(self, min: T, max: T, mut compare: F) -> Self
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert!(
+            compare(&min, &max) != Ordering::Greater,
+            "min > max in GenericArray::clamp_each_by"
+        );
+
+        self.map(|x| {
+            if compare(&x, &min) == Ordering::Less {
+                min.clone()
+            } else if compare(&x, &max) == Ordering::Greater {
+                max.clone()
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Restricts each element to the interval `[min, max]`, in place.
+    ///
+    /// See [`clamp_each`](GenericArray::clamp_each) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[inline]
+    pub fn clamp_each_mut(&mut self, min: T, max: T)
+    where
+        T: Ord + Copy,
+    {
+        assert!(min <= max, "min > max in GenericArray::clamp_each_mut");
+
+        for x in self.iter_mut() {
+            *x = (*x).clamp(min, max);
+        }
+    }
+
+    /// Computes the sum of each contiguous window of `W` elements, in order.
+    ///
+    /// There are `N - W + 1` windows, with `W <= N` checked at compile-time.
+    /// Each window's sum is derived from the previous one by subtracting the element
+    /// that leaves the window and adding the one that enters it, so only `O(N)` operations
+    /// are performed. For floats, this may accumulate more rounding error than summing
+    /// each window separately.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U3};
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(a.window_sums::<U3>(), arr![6, 9, 12]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` is zero, which is only possible with a non-canonical
+    /// length such as `UInt<UTerm, B0>` that `typenum` still considers [`NonZero`].
+    #[track_caller]
+    #[inline]
+    pub fn window_sums<W>(&self) -> GenericArray<T, Add1<Diff<N, W>>>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy,
+        W: ArrayLength + NonZero,
+        N: Sub<W>,
+        Diff<N, W>: Add<B1>,
+        Add1<Diff<N, W>>: ArrayLength,
+    {
+        assert!(W::USIZE != 0, "empty window in GenericArray::window_sums");
+
+        let mut sum = self[1..W::USIZE].iter().fold(self[0], |acc, &x| acc + x);
+
+        GenericArray::generate(|i| {
+            if i > 0 {
+                sum = sum - self[i - 1] + self[i + W::USIZE - 1];
+            }
+
+            sum
+        })
+    }
+
+    /// Clones the elements of the array followed by the elements of `other` into a new array
+    /// of length `M`, or returns `None` if `N + other.len()` is not equal to `M`.
+    ///
+    /// This is useful for assembling a fixed-size output from a fixed-size prefix and
+    /// variable-length data of a known total length.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::{U4, U5}};
+    /// let header = arr![0xFF, 0x01];
+    ///
+    /// let packet: Option<GenericArray<u8, U4>> = header.try_concat_to(&[7, 8]);
+    /// assert_eq!(packet, Some(arr![0xFF, 0x01, 7, 8]));
+    ///
+    /// assert_eq!(header.try_concat_to::<U5>(&[7, 8]), None);
+    /// ```
+    #[inline]
+    pub fn try_concat_to<M: ArrayLength>(&self, other: &[T]) -> Option<GenericArray<T, M>>
+    where
+        T: Clone,
+    {
+        if N::USIZE + other.len() != M::USIZE {
+            return None;
+        }
+
+        Some(FromIterator::from_iter(self.iter().chain(other).cloned()))
+    }
+
+    /// Consumes the array, moving its elements into an array of length `M`.
+    ///
+    /// If `M < N`, the extra elements at the end are dropped. If `M > N`, the new array
+    /// is padded at the end with [`T::default()`](Default::default).
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, typenum::{U2, U5}};
+    /// let a = arr![1, 2, 3];
+    ///
+    /// assert_eq!(a.resize::<U2>(), arr![1, 2]);
+    /// assert_eq!(a.resize::<U5>(), arr![1, 2, 3, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn resize<M: ArrayLength>(self) -> GenericArray<T, M>
+    where
+        T: Default,
+    {
+        let mut iter = self.into_iter();
+
+        GenericArray::generate(|_| iter.next().unwrap_or_default())
+    }
+
+    /// Computes the valid (unpadded) 1D convolution of the array with `kernel`.
+    ///
+    /// Element `i` of the output is the sum of `self[i + j] * kernel[K - 1 - j]` for each `j` in `0..K`,
+    /// so the kernel is applied reversed, as in the mathematical definition of convolution.
+    /// Reverse the kernel beforehand to compute a cross-correlation instead.
+    ///
+    /// There are `N - K + 1` outputs, with `K <= N` checked at compile-time.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let signal = arr![1, 2, 3, 4];
+    /// let kernel = arr![1, 0, -1];
+    ///
+    /// assert_eq!(signal.convolve(&kernel), arr![2, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the kernel is empty, which is only possible with a non-canonical
+    /// length such as `UInt<UTerm, B0>` that `typenum` still considers [`NonZero`].
+    #[track_caller]
+    #[inline]
+    pub fn convolve<K>(&self, kernel: &GenericArray<T, K>) -> GenericArray<T, Add1<Diff<N, K>>>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Copy,
+        K: ArrayLength + NonZero,
+        N: Sub<K>,
+        Diff<N, K>: Add<B1>,
+        Add1<Diff<N, K>>: ArrayLength,
+    {
+        assert!(K::USIZE != 0, "empty kernel in GenericArray::convolve");
+
+        GenericArray::generate(|i| {
+            let window = &self[i..i + K::USIZE];
+
+            window
+                .iter()
+                .zip(kernel.iter().rev())
+                .map(|(&x, &k)| x * k)
+                .reduce(|acc, x| acc + x)
+                .expect("kernel is non-empty")
+        })
+    }
+
+    /// Rotates the array in place such that the first `mid` elements move to the end,
+    /// or returns `Err(mid)` without modifying the array if `mid` is not in `0..=N`.
+    ///
+    /// This is the non-panicking equivalent of [`slice::rotate_left`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4];
+    ///
+    /// assert_eq!(a.try_rotate_left(1), Ok(()));
+    /// assert_eq!(a, arr![2, 3, 4, 1]);
+    ///
+    /// assert_eq!(a.try_rotate_left(5), Err(5));
+    /// assert_eq!(a, arr![2, 3, 4, 1]);
+    /// ```
+    #[inline]
+    pub fn try_rotate_left(&mut self, mid: usize) -> Result<(), usize> {
+        if mid > N::USIZE {
+            return Err(mid);
+        }
+
+        self.rotate_left(mid);
+
+        Ok(())
+    }
+
+    /// Rotates the array in place such that the last `k` elements move to the front,
+    /// or returns `Err(k)` without modifying the array if `k` is not in `0..=N`.
+    ///
+    /// This is the non-panicking equivalent of [`slice::rotate_right`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4];
+    ///
+    /// assert_eq!(a.try_rotate_right(1), Ok(()));
+    /// assert_eq!(a, arr![4, 1, 2, 3]);
+    ///
+    /// assert_eq!(a.try_rotate_right(5), Err(5));
+    /// ```
+    #[inline]
+    pub fn try_rotate_right(&mut self, k: usize) -> Result<(), usize> {
+        if k > N::USIZE {
+            return Err(k);
+        }
+
+        self.rotate_right(k);
+
+        Ok(())
+    }
+
+    /// Consumes the array, rotating it such that the first `K` elements move to the end,
+    /// with `K <= N` checked at compile-time.
+    ///
+    /// This is the by-value equivalent of [`rotate_left(K::USIZE)`](slice::rotate_left), moving each
+    /// element directly into its rotated position. Since the amount is a type-level constant,
+    /// it can never be out of range, and the compiler can specialize on it.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U2};
+    /// let a = arr![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(a.rotate_left_by::<U2>(), arr![3, 4, 5, 1, 2]);
+    /// ```
+    ///
+    /// Rotation amounts greater than the array length will not compile:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{arr, typenum::U4};
+    /// let _ = arr![1, 2, 3].rotate_left_by::<U4>();
+    /// ```
+    #[inline]
+    pub fn rotate_left_by<K>(self) -> Self
+    where
+        K: ArrayLength + IsLessOrEqual<N, Output = True>,
+    {
+        self.rotated_map(K::USIZE, |x| x)
+    }
+
+    /// Reduces the array to a single element by combining pairs of elements in a balanced tree,
+    /// returning `None` for zero-length arrays.
+    ///
+    /// Adjacent elements are combined first, then adjacent results, and so on, so
+    /// for four elements the result is `f(f(a, b), f(c, d))`. The left operand always
+    /// precedes the right operand in the original order, so `f` only needs to be associative
+    /// to give the same result as [`reduce`](GenericArray::reduce).
+    ///
+    /// For floating-point sums, the rounding error grows with the depth of the tree, `log2(N)`,
+    /// rather than with `N` as it does when reducing from left to right.
+    ///
+    /// If the combining function panics, any remaining elements and intermediate results are dropped.
+    ///
+    /// ```
+    /// # use generic_array::{arr, functional::FunctionalSequence};
+    /// let a = arr!["a", "b", "c", "d", "e"].map(String::from);
+    ///
+    /// assert_eq!(a.tree_reduce(|l, r| format!("({l}{r})")).unwrap(), "(((ab)(cd))e)");
+    /// ```
+    #[inline]
+    pub fn tree_reduce<F>(self, mut f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let mut slots = self.map(Some);
+        let mut stride = 1;
+
+        while stride < N::USIZE {
+            for i in (0..N::USIZE - stride).step_by(stride * 2) {
+                if let (Some(l), Some(r)) = (slots[i].take(), slots[i + stride].take()) {
+                    slots[i] = Some(f(l, r));
+                }
+            }
+
+            stride *= 2;
+        }
+
+        slots.first_mut()?.take()
+    }
+
+    /// Counts the number of runs of consecutive equal elements.
+    ///
+    /// This is the length that [`dedup_in_place`](GenericArray::dedup_in_place) would return,
+    /// computed without modifying the array. For a sorted array, this is the number of distinct elements.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![1, 1, 2, 3, 3, 3, 1].count_distinct(), 4);
+    /// ```
+    #[inline]
+    pub fn count_distinct(&self) -> usize
+    where
+        T: PartialEq,
+    {
+        if N::USIZE == 0 {
+            return 0;
+        }
+
+        1 + self.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    /// Removes consecutive repeated elements by moving the first element of each run to the front
+    /// of the array, returning the number of elements kept.
+    ///
+    /// After this call, `self[..len]` holds the deduplicated elements in their original order,
+    /// and `self[len..]` holds the removed duplicates in an unspecified order. No elements
+    /// are dropped, so the tail remains valid and can be used or overwritten as needed.
+    ///
+    /// This is the array equivalent of [`Vec::dedup`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup).
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 1, 2, 3, 3, 3, 1];
+    /// let len = a.dedup_in_place();
+    ///
+    /// assert_eq!(len, 4);
+    /// assert_eq!(a[..len], [1, 2, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup_in_place(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        if N::USIZE == 0 {
+            return 0;
+        }
+
+        let mut len = 1;
+
+        for i in 1..N::USIZE {
+            if self[i] != self[len - 1] {
+                self.swap(i, len);
+                len += 1;
+            }
+        }
+
+        len
+    }
+
+    /// Counts the elements into `B` bins in a single pass, where `bin` returns the bin index
+    /// of each element.
+    ///
+    /// The counts always sum to `N`.
+    ///
+    /// ```
+    /// # use generic_array::{arr, typenum::U4};
+    /// let scores = arr![12, 47, 3, 88, 51, 99, 20];
+    /// let bins = scores.histogram::<U4>(|&x| x / 25);
+    ///
+    /// assert_eq!(bins, arr![3, 1, 1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bin` returns an index that is not less than `B`.
+    #[track_caller]
+    #[inline]
+    pub fn histogram<B: ArrayLength>(&self, bin: impl Fn(&T) -> usize) -> GenericArray<u32, B> {
+        let mut counts = GenericArray::<u32, B>::default();
+
+        for x in self.iter() {
+            let i = bin(x);
+
+            assert!(
+                i < B::USIZE,
+                "bin index {} is out of range for {} bins",
+                i,
+                B::USIZE
+            );
+
+            counts[i] += 1;
+        }
+
+        counts
+    }
+
+    /// Creates an array by cloning the elements of `source` at each of the given `indices`,
+    /// so that `out[i] = source[indices[i]].clone()`.
+    ///
+    /// Indices may repeat and appear in any order.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray};
+    /// let source = [10, 20, 30, 40, 50];
+    ///
+    /// assert_eq!(GenericArray::gather(&source, &arr![4, 0, 0, 2]), arr![50, 10, 10, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `source`. See
+    /// [`checked_gather`](GenericArray::checked_gather) for a non-panicking version.
+    #[inline]
+    pub fn gather<const SRC: usize>(source: &[T; SRC], indices: &GenericArray<usize, N>) -> Self
+    where
+        T: Clone,
+    {
+        GenericArray::generate(|i| source[indices[i]].clone())
+    }
+
+    /// Creates an array by cloning the elements of `source` at each of the given `indices`,
+    /// or returns `None` if any index is out of bounds for `source`.
+    ///
+    /// No elements are cloned past the first out-of-bounds index.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray};
+    /// let source = ['a', 'b', 'c'];
+    ///
+    /// assert_eq!(GenericArray::checked_gather(&source, &arr![2, 1]), Some(arr!['c', 'b']));
+    /// assert_eq!(GenericArray::checked_gather(&source, &arr![0, 3]), None);
+    /// ```
+    #[inline]
+    pub fn checked_gather<const SRC: usize>(
+        source: &[T; SRC],
+        indices: &GenericArray<usize, N>,
+    ) -> Option<Self>
+    where
+        T: Clone,
+    {
+        GenericArray::try_generate(|i| source.get(indices[i]).cloned().ok_or(())).ok()
+    }
+
+    /// Moves each element to the position given by `indices`, so that `out[indices[i]] = self[i]`.
+    ///
+    /// This is the inverse of reordering with `out[i] = self[indices[i]]`, as done by
+    /// [`gather`](GenericArray::gather), and can be used to restore the original order
+    /// after a permuted computation.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray};
+    /// let p = arr![2, 0, 3, 1];
+    /// let permuted = GenericArray::gather(&['a', 'b', 'c', 'd'], &p);
+    ///
+    /// assert_eq!(permuted, arr!['c', 'a', 'd', 'b']);
+    /// assert_eq!(permuted.permute_inverse(p), arr!['a', 'b', 'c', 'd']);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not a permutation of `0..N`. See
+    /// [`checked_permute_inverse`](GenericArray::checked_permute_inverse) for a non-panicking version.
+    #[track_caller]
+    #[inline]
+    pub fn permute_inverse(self, indices: GenericArray<usize, N>) -> Self {
+        match self.checked_permute_inverse(indices) {
+            Ok(array) => array,
+            Err(_) => panic!("indices are not a permutation in GenericArray::permute_inverse"),
+        }
+    }
+
+    /// Moves each element to the position given by `indices`, so that `out[indices[i]] = self[i]`,
+    /// or returns the array unchanged as `Err` if `indices` is not a permutation of `0..N`.
+    ///
+    /// The indices are validated before any elements are moved.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![10, 20, 30];
+    ///
+    /// assert_eq!(a.checked_permute_inverse(arr![1, 2, 0]), Ok(arr![30, 10, 20]));
+    /// assert_eq!(a.checked_permute_inverse(arr![1, 1, 0]), Err(a));
+    /// assert_eq!(a.checked_permute_inverse(arr![0, 1, 3]), Err(a));
+    /// ```
+    pub fn checked_permute_inverse(self, indices: GenericArray<usize, N>) -> Result<Self, Self> {
+        let mut seen = GenericArray::<bool, N>::default();
+
+        for &j in indices.iter() {
+            match seen.get_mut(j) {
+                Some(seen) if !*seen => *seen = true,
+                _ => return Err(self),
+            }
+        }
+
+        let source = ManuallyDrop::new(self);
+        let mut array = GenericArray::uninit();
+
+        // SAFETY: `indices` is a permutation, so each source element is read exactly once
+        // and every destination element is written exactly once, with nothing that can panic.
+        unsafe {
+            for (src, &j) in source.iter().zip(indices.iter()) {
+                array.get_unchecked_mut(j).write(ptr::read(src));
+            }
+
+            Ok(GenericArray::assume_init(array))
+        }
+    }
+
+    /// Compares the array element-wise with `other`, returning a mask
+    /// where `mask[i]` is `self[i] == other[i]`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3, 4];
+    ///
+    /// assert_eq!(a.eq_mask(&arr![1, 0, 3, 0]), arr![true, false, true, false]);
+    /// ```
+    #[inline]
+    pub fn eq_mask(&self, other: &Self) -> GenericArray<bool, N>
+    where
+        T: PartialEq,
+    {
+        GenericArray::generate(|i| self[i] == other[i])
+    }
+
+    /// Compares the array element-wise with `other`, returning a mask
+    /// where `mask[i]` is `self[i] < other[i]`.
+    ///
+    /// Incomparable elements, such as `NaN`, produce `false`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1.0, 2.0, f32::NAN];
+    ///
+    /// assert_eq!(a.lt_mask(&arr![2.0, 2.0, 0.0]), arr![true, false, false]);
+    /// ```
+    #[inline]
+    pub fn lt_mask(&self, other: &Self) -> GenericArray<bool, N>
+    where
+        T: PartialOrd,
+    {
+        GenericArray::generate(|i| self[i] < other[i])
+    }
+
+    /// Compares the array element-wise with `other`, returning a mask
+    /// where `mask[i]` is `self[i] > other[i]`.
+    ///
+    /// Incomparable elements, such as `NaN`, produce `false`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1.0, 2.0, f32::NAN];
+    ///
+    /// assert_eq!(a.gt_mask(&arr![0.0, 2.0, 0.0]), arr![true, false, false]);
+    /// ```
+    #[inline]
+    pub fn gt_mask(&self, other: &Self) -> GenericArray<bool, N>
+    where
+        T: PartialOrd,
+    {
+        GenericArray::generate(|i| self[i] > other[i])
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<Option<T>, N> {
+    /// Converts an array of `Option`s into an `Option` of an array, returning `Some`
+    /// only if every element is `Some`.
+    ///
+    /// Stops at the first `None`, dropping any values already taken out of a `Some`,
+    /// along with the remaining elements of the source array.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert_eq!(arr![Some(1), Some(2), Some(3)].transpose_options(), Some(arr![1, 2, 3]));
+    /// assert_eq!(arr![Some(1), None, Some(3)].transpose_options(), None);
+    /// ```
+    #[inline]
+    pub fn transpose_options(self) -> Option<GenericArray<T, N>> {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+            let (src_iter, src_position) = source.iter_position();
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for (dst, src) in dst_iter.zip(src_iter) {
+                    let value = ptr::read(src);
+
+                    *src_position += 1;
+
+                    dst.write(value?);
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            Some(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+}
+
+impl<T, E, N: ArrayLength> GenericArray<Result<T, E>, N> {
+    /// Converts an array of `Result`s into a `Result` of an array, returning `Ok`
+    /// only if every element is `Ok`, otherwise the first `Err`.
+    ///
+    /// Stops at the first `Err`, dropping any values already taken out of an `Ok`,
+    /// along with the remaining elements of the source array.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![Ok(1), Err("two"), Err("three")];
+    ///
+    /// assert_eq!(a.transpose_results(), Err("two"));
+    /// assert_eq!(arr![Ok::<_, ()>(1), Ok(2)].transpose_results(), Ok(arr![1, 2]));
+    /// ```
+    #[inline]
+    pub fn transpose_results(self) -> Result<GenericArray<T, N>, E> {
+        unsafe {
+            let mut source = ArrayConsumer::new(self);
+            let (src_iter, src_position) = source.iter_position();
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for (dst, src) in dst_iter.zip(src_iter) {
+                    let value = ptr::read(src);
+
+                    *src_position += 1;
+
+                    dst.write(value?);
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+
+    /// Maps each `Ok` value with `f`, leaving any `Err` values unchanged.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![Ok(1), Err("bad"), Ok(3)];
+    ///
+    /// assert_eq!(a.map_ok(|x| x * 10), arr![Ok(10), Err("bad"), Ok(30)]);
+    /// ```
+    #[inline]
+    pub fn map_ok<U, F>(self, mut f: F) -> GenericArray<Result<U, E>, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(|x| x.map(&mut f))
+    }
+}
@@ -0,0 +1,95 @@
+//! Parsing a `GenericArray` from delimited text
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder};
+
+/// Error for [`parse_delimited`](GenericArray::parse_delimited)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError<E> {
+    /// The input did not contain exactly as many values as the array length.
+    Length,
+    /// The value at `index` could not be parsed.
+    Element {
+        /// Index of the value that failed to parse
+        index: usize,
+        /// Error returned by the element's [`FromStr`] implementation
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Length => {
+                f.write_str("ParseError: Number of values does not match GenericArray length")
+            }
+            ParseError::Element { index, error } => {
+                write!(f, "ParseError: Invalid value at index {}: {}", index, error)
+            }
+        }
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Parses exactly `N` values separated by `sep`.
+    ///
+    /// Each value is passed as-is to [`FromStr::from_str`], so surrounding whitespace
+    /// is not trimmed. An empty string is only accepted for zero-length arrays.
+    ///
+    /// If a value fails to parse, any already parsed elements are dropped.
+    ///
+    /// ```
+    /// # use generic_array::{arr, GenericArray, ParseError, typenum::U4};
+    /// let a = GenericArray::<u8, U4>::parse_delimited("1,2,3,4", ',');
+    /// assert_eq!(a, Ok(arr![1, 2, 3, 4]));
+    ///
+    /// let a = GenericArray::<u8, U4>::parse_delimited("1,2,3", ',');
+    /// assert_eq!(a, Err(ParseError::Length));
+    ///
+    /// let a = GenericArray::<u8, U4>::parse_delimited("1,2,x,4", ',');
+    /// assert!(matches!(a, Err(ParseError::Element { index: 2, .. })));
+    /// ```
+    pub fn parse_delimited<E>(s: &str, sep: char) -> Result<Self, ParseError<E>>
+    where
+        T: FromStr<Err = E>,
+    {
+        if N::USIZE == 0 {
+            return match s.is_empty() {
+                true => Ok(unsafe { GenericArray::assume_init(GenericArray::uninit()) }),
+                false => Err(ParseError::Length),
+            };
+        }
+
+        let mut values = s.split(sep);
+
+        unsafe {
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for (index, dst) in dst_iter.enumerate() {
+                    let value = values.next().ok_or(ParseError::Length)?;
+
+                    dst.write(
+                        value
+                            .parse()
+                            .map_err(|error| ParseError::Element { index, error })?,
+                    );
+
+                    *dst_position += 1;
+                }
+            }
+
+            if values.next().is_some() {
+                return Err(ParseError::Length);
+            }
+
+            builder.finish();
+            Ok(IntrusiveArrayBuilder::array_assume_init(array))
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Searching and querying the elements of a `GenericArray`
+
+use crate::{ArrayLength, GenericArray, GenericSequence};
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Returns the index of the first element equal to `x`, or `None` if there is no such element.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3, 2];
+    ///
+    /// assert_eq!(a.index_of(&2), Some(1));
+    /// assert_eq!(a.index_of(&4), None);
+    /// ```
+    #[inline]
+    pub fn index_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|y| y == x)
+    }
+
+    /// Returns the index of the last element equal to `x`, or `None` if there is no such element.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3, 2];
+    ///
+    /// assert_eq!(a.last_index_of(&2), Some(3));
+    /// assert_eq!(a.last_index_of(&4), None);
+    /// ```
+    #[inline]
+    pub fn last_index_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().rposition(|y| y == x)
+    }
+
+    /// Returns a reference to the element `n` positions from the end of the array,
+    /// which is `self[N - 1 - n]`, or `None` if `n >= N`.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 2, 3];
+    ///
+    /// assert_eq!(a.nth_from_end(0), Some(&3));
+    /// assert_eq!(a.nth_from_end(2), Some(&1));
+    /// assert_eq!(a.nth_from_end(3), None);
+    /// ```
+    #[inline]
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        match n < N::USIZE {
+            true => Some(&self[N::USIZE - 1 - n]),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element `n` positions from the end of the array,
+    /// which is `self[N - 1 - n]`, or `None` if `n >= N`.
+    #[inline]
+    pub fn nth_from_end_mut(&mut self, n: usize) -> Option<&mut T> {
+        match n < N::USIZE {
+            true => Some(&mut self[N::USIZE - 1 - n]),
+            false => None,
+        }
+    }
+
+    /// Returns the index of the element that gives the maximum value from the key function,
+    /// or `None` if the array is empty.
+    ///
+    /// If several elements are equally maximum, the index of the last one is returned,
+    /// matching [`Iterator::max_by_key`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![-3, 1, 3, -2];
+    ///
+    /// assert_eq!(a.position_max_by_key(|x: &i32| x.abs()), Some(2));
+    /// ```
+    #[inline]
+    pub fn position_max_by_key<K, F>(&self, mut f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter()
+            .enumerate()
+            .max_by_key(|&(_, x)| f(x))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the index of the element that gives the minimum value from the key function,
+    /// or `None` if the array is empty.
+    ///
+    /// If several elements are equally minimum, the index of the first one is returned,
+    /// matching [`Iterator::min_by_key`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![-3, 1, 3, -1];
+    ///
+    /// assert_eq!(a.position_min_by_key(|x: &i32| x.abs()), Some(1));
+    /// ```
+    #[inline]
+    pub fn position_min_by_key<K, F>(&self, mut f: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter()
+            .enumerate()
+            .min_by_key(|&(_, x)| f(x))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns references to the minimum and maximum elements of the array as `(min, max)`,
+    /// or `None` if the array is empty.
+    ///
+    /// Both extremes are found in a single pass by comparing elements pairwise,
+    /// which takes roughly `3N/2` comparisons rather than the `2N` of separate
+    /// [`min`](Iterator::min) and [`max`](Iterator::max) calls.
+    ///
+    /// If several elements are equally minimum, the first one is returned, and if several
+    /// elements are equally maximum, the last one is returned, matching [`Iterator::min`]
+    /// and [`Iterator::max`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![3, 1, 4, 1, 5, 9, 2, 6];
+    ///
+    /// assert_eq!(a.min_max(), Some((&1, &9)));
+    /// ```
+    #[inline]
+    pub fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        min_max_position(N::USIZE, |i, j| self[i] < self[j])
+            .map(|(min, max)| (&self[min], &self[max]))
+    }
+
+    /// Returns references to the elements that give the minimum and maximum values
+    /// from the key function as `(min, max)`, or `None` if the array is empty.
+    ///
+    /// The key function is called exactly once per element. Ties are resolved as in
+    /// [`min_max`](GenericArray::min_max): the first minimum and the last maximum are returned.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![-3, 1, 3, -1];
+    ///
+    /// assert_eq!(a.min_max_by_key(|x: &i32| x.abs()), Some((&1, &3)));
+    /// ```
+    #[inline]
+    pub fn min_max_by_key<K, F>(&self, mut f: F) -> Option<(&T, &T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let keys = GenericArray::<K, N>::generate(|i| f(&self[i]));
+
+        min_max_position(N::USIZE, |i, j| keys[i] < keys[j])
+            .map(|(min, max)| (&self[min], &self[max]))
+    }
+}
+
+/// Finds the positions of the first minimum and last maximum of `len` elements,
+/// where `lt(i, j)` compares the elements at `i` and `j`.
+fn min_max_position(
+    len: usize,
+    mut lt: impl FnMut(usize, usize) -> bool,
+) -> Option<(usize, usize)> {
+    let (mut min, mut max, start) = match len {
+        0 => return None,
+        // odd lengths seed both extremes with the first element
+        _ if len % 2 == 1 => (0, 0, 1),
+        _ if lt(1, 0) => (1, 0, 2),
+        _ => (0, 1, 2),
+    };
+
+    let mut i = start;
+
+    while i < len {
+        // within a pair, equal elements order the first as smaller and the last as larger
+        let (small, large) = if lt(i + 1, i) { (i + 1, i) } else { (i, i + 1) };
+
+        if lt(small, min) {
+            min = small;
+        }
+
+        if !lt(large, max) {
+            max = large;
+        }
+
+        i += 2;
+    }
+
+    Some((min, max))
+}
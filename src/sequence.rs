@@ -29,6 +29,31 @@ pub unsafe trait GenericSequence<T>: Sized + IntoIterator {
     where
         F: FnMut(usize) -> T;
 
+    /// Initializes a new sequence instance using the given fallible function,
+    /// stopping at the first error.
+    ///
+    /// If the generator function returns an error or panics while initializing the sequence,
+    /// any already initialized elements will be dropped.
+    ///
+    /// ```rust
+    /// # use generic_array::{arr, GenericArray, sequence::GenericSequence, typenum::U3};
+    /// let a = GenericArray::<u8, U3>::try_generate(|i| u8::try_from(i * 100));
+    /// assert_eq!(a, Ok(arr![0, 100, 200]));
+    ///
+    /// let b = GenericArray::<u8, U3>::try_generate(|i| u8::try_from(i * 200));
+    /// assert!(b.is_err());
+    /// ```
+    #[inline]
+    fn try_generate<E, F>(f: F) -> Result<Self::Sequence, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        let array = GenericArray::<T, Self::Length>::try_generate(f)?;
+
+        // exactly `Self::Length` elements, so this won't panic
+        Ok(FromIterator::from_iter(array))
+    }
+
     /// Treats `self` as the right-hand operand in a zip operation
     ///
     /// This is optimized for stack-allocated `GenericArray`s
@@ -93,6 +118,14 @@ where
     {
         S::generate(f)
     }
+
+    #[inline(always)]
+    fn try_generate<E, F>(f: F) -> Result<Self::Sequence, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        S::try_generate(f)
+    }
 }
 
 unsafe impl<'a, T: 'a, S: GenericSequence<T>> GenericSequence<T> for &'a mut S
@@ -109,6 +142,14 @@ where
     {
         S::generate(f)
     }
+
+    #[inline(always)]
+    fn try_generate<E, F>(f: F) -> Result<Self::Sequence, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        S::try_generate(f)
+    }
 }
 
 /// Defines any `GenericSequence` which can be lengthened or extended by appending
@@ -0,0 +1,267 @@
+//! Sorting and ordering the elements of a `GenericArray`
+
+use core::cmp::Ordering;
+use core::ops::Add;
+use core::ptr;
+
+use typenum::operator_aliases::Sum;
+
+use crate::{ArrayConsumer, ArrayLength, GenericArray, IntrusiveArrayBuilder};
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Merges two sorted arrays into a single sorted array.
+    ///
+    /// The merge is stable, so elements of `self` are placed before equal elements of `other`.
+    /// If either input is not sorted, the output order is unspecified but all elements are still
+    /// present.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![1, 3, 5, 7];
+    /// let b = arr![2, 3, 6];
+    ///
+    /// assert_eq!(a.merge(b), arr![1, 2, 3, 3, 5, 6, 7]);
+    /// ```
+    #[inline]
+    pub fn merge<M>(self, other: GenericArray<T, M>) -> GenericArray<T, Sum<N, M>>
+    where
+        T: Ord,
+        M: ArrayLength,
+        N: Add<M>,
+        Sum<N, M>: ArrayLength,
+    {
+        self.merge_by(other, T::cmp)
+    }
+
+    /// Merges two arrays sorted by the comparator function `compare` into a single sorted array.
+    ///
+    /// The merge is stable, so elements of `self` are placed before equal elements of `other`.
+    ///
+    /// If the comparator function panics, any already merged elements in the new array
+    /// will be dropped, AND any unused elements in both source arrays will also be dropped.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let a = arr![(1, 'a'), (2, 'a')];
+    /// let b = arr![(1, 'b'), (3, 'b')];
+    ///
+    /// assert_eq!(
+    ///     a.merge_by(b, |x, y| x.0.cmp(&y.0)),
+    ///     arr![(1, 'a'), (1, 'b'), (2, 'a'), (3, 'b')]
+    /// );
+    /// ```
+    #[inline]
+    pub fn merge_by<M, F>(
+        self,
+        other: GenericArray<T, M>,
+        mut compare: F,
+    ) -> GenericArray<T, Sum<N, M>>
+    where
+        M: ArrayLength,
+        N: Add<M>,
+        Sum<N, M>: ArrayLength,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        unsafe {
+            let mut left = ArrayConsumer::new(self);
+            let mut right = ArrayConsumer::new(other);
+
+            let (left_iter, left_position) = left.iter_position();
+            let (right_iter, right_position) = right.iter_position();
+
+            let (left_slice, right_slice) = (left_iter.as_slice(), right_iter.as_slice());
+
+            let mut array = GenericArray::uninit();
+            let mut builder = IntrusiveArrayBuilder::new(&mut array);
+
+            {
+                let (dst_iter, dst_position) = builder.iter_position();
+
+                for dst in dst_iter {
+                    let take_right = match (
+                        left_slice.get(*left_position),
+                        right_slice.get(*right_position),
+                    ) {
+                        (Some(l), Some(r)) => compare(l, r) == Ordering::Greater,
+                        (None, _) => true,
+                        (_, None) => false,
+                    };
+
+                    let value = match take_right {
+                        true => {
+                            let value = ptr::read(right_slice.get_unchecked(*right_position));
+                            *right_position += 1;
+                            value
+                        }
+                        false => {
+                            let value = ptr::read(left_slice.get_unchecked(*left_position));
+                            *left_position += 1;
+                            value
+                        }
+                    };
+
+                    dst.write(value);
+
+                    *dst_position += 1;
+                }
+            }
+
+            builder.finish();
+            IntrusiveArrayBuilder::array_assume_init(array)
+        }
+    }
+
+    /// Sorts the array in place with a comparator function that may fail.
+    ///
+    /// The sort is stable. On the first error, sorting stops and the error is returned,
+    /// leaving the elements in an unspecified order, but with every element still present.
+    ///
+    /// This is a binary insertion sort, performing `O(N log N)` comparisons but up to `O(N²)`
+    /// element moves, so it is best suited to small arrays.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr!["3", "1", "2"];
+    ///
+    /// let res = a.try_sort_by(|x, y| {
+    ///     Ok::<_, core::num::ParseIntError>(x.parse::<u8>()?.cmp(&y.parse()?))
+    /// });
+    /// assert!(res.is_ok());
+    /// assert_eq!(a, arr!["1", "2", "3"]);
+    /// ```
+    pub fn try_sort_by<E, F>(&mut self, mut compare: F) -> Result<(), E>
+    where
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        for i in 1..N::USIZE {
+            // find the first position in the sorted prefix greater than the new element
+            let (mut lo, mut hi) = (0, i);
+
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+
+                match compare(&self[i], &self[mid])? {
+                    Ordering::Less => hi = mid,
+                    _ => lo = mid + 1,
+                }
+            }
+
+            self[lo..=i].rotate_right(1);
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the elements of this array are sorted in non-descending order.
+    ///
+    /// This has the same semantics as [`slice::is_sorted`]: two adjacent elements
+    /// that are incomparable, such as any float compared to `NaN`, make the array unsorted.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert!(arr![1, 2, 2, 9].is_sorted());
+    /// assert!(!arr![1, 3, 2, 4].is_sorted());
+    /// assert!(!arr![0.0, f32::NAN, 1.0].is_sorted());
+    /// ```
+    #[inline]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.as_slice().is_sorted()
+    }
+
+    /// Checks if the elements of this array are sorted in non-descending order
+    /// of the keys extracted by the function `f`.
+    ///
+    /// This has the same semantics as [`slice::is_sorted_by_key`].
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// assert!(arr!["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    /// assert!(!arr![-2i32, 1, 3].is_sorted_by_key(|x| x.abs()));
+    /// ```
+    #[inline]
+    pub fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K,
+    {
+        self.as_slice().is_sorted_by_key(f)
+    }
+
+    /// Reorders the elements of the array so that all elements satisfying `pred` come before
+    /// all elements that do not, returning the number of elements that satisfy it.
+    ///
+    /// The returned index is the partition point, so `self[..i]` satisfies `pred`
+    /// and `self[i..]` does not. The partition is unstable, so the relative order of elements
+    /// within each side is not preserved. Elements are only swapped, never cloned, and
+    /// `pred` is called exactly once per element.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![1, 2, 3, 4, 5, 6, 7];
+    /// let i = a.partition_in_place(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(i, 3);
+    /// assert!(a[..i].iter().all(|x| x % 2 == 0));
+    /// assert!(a[i..].iter().all(|x| x % 2 == 1));
+    /// ```
+    #[inline]
+    pub fn partition_in_place<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (mut left, mut right) = (0, N::USIZE);
+
+        loop {
+            while left < right && pred(&self[left]) {
+                left += 1;
+            }
+
+            // `self[left]` is already known to fail, so don't test it again
+            while left + 1 < right && !pred(&self[right - 1]) {
+                right -= 1;
+            }
+
+            if left + 1 >= right {
+                return left;
+            }
+
+            // `self[left]` fails and `self[right - 1]` satisfies the predicate
+            right -= 1;
+            self.swap(left, right);
+            left += 1;
+        }
+    }
+
+    /// Reorders the array so that the element at index `k` is the one that would be there
+    /// if the array were fully sorted, returning a mutable reference to it.
+    ///
+    /// All elements before index `k` are less than or equal to it, and all elements after are
+    /// greater than or equal to it, in no particular order. This is the array equivalent of
+    /// [`slice::select_nth_unstable`], which it uses for an `O(N)` worst case, and is useful
+    /// for finding order statistics such as the median without fully sorting. The slice method
+    /// remains available through deref when the partitions on either side are also needed.
+    ///
+    /// ```
+    /// # use generic_array::arr;
+    /// let mut a = arr![9, 1, 8, 2, 7, 3, 6];
+    ///
+    /// let median = *a.select_nth(3);
+    /// assert_eq!(median, 6);
+    /// assert!(a[..3].iter().all(|&x| x <= median));
+    /// assert!(a[4..].iter().all(|&x| x >= median));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= N`.
+    #[inline]
+    pub fn select_nth(&mut self, k: usize) -> &mut T
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().select_nth_unstable(k).1
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "base64")]
+
+use std::ops::{Add, Div, Mul};
+
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{Quot, Sum, U0, U1, U2, U3, U4, U5, U6, U64};
+use generic_array::{arr, ArrayLength, Base64Error, Base64Len, GenericArray};
+
+fn round_trip<N>(input: &[u8], expected: &[u8])
+where
+    N: ArrayLength + Add<U2>,
+    Sum<N, U2>: Div<U3>,
+    Quot<Sum<N, U2>, U3>: Mul<U4>,
+    Base64Len<N>: ArrayLength,
+{
+    let array = GenericArray::<u8, N>::from_slice(input);
+    let encoded = array.to_base64();
+
+    assert_eq!(encoded.as_slice(), expected);
+    assert_eq!(
+        GenericArray::<u8, N>::from_base64(&encoded).as_ref(),
+        Ok(array)
+    );
+}
+
+#[test]
+fn test_base64_rfc4648_vectors() {
+    round_trip::<U0>(b"", b"");
+    round_trip::<U1>(b"f", b"Zg==");
+    round_trip::<U2>(b"fo", b"Zm8=");
+    round_trip::<U3>(b"foo", b"Zm9v");
+    round_trip::<U4>(b"foob", b"Zm9vYg==");
+    round_trip::<U5>(b"fooba", b"Zm9vYmE=");
+    round_trip::<U6>(b"foobar", b"Zm9vYmFy");
+}
+
+#[test]
+fn test_base64_round_trip_all_bytes() {
+    let a = GenericArray::<u8, U64>::generate(|i| (i * 4) as u8);
+    let b = GenericArray::<u8, U64>::generate(|i| (i * 4 + 3) as u8);
+
+    for array in [a, b] {
+        let encoded = array.to_base64();
+        assert_eq!(encoded.len(), 88);
+        assert_eq!(GenericArray::from_base64(&encoded), Ok(array));
+    }
+}
+
+#[test]
+fn test_base64_errors() {
+    type A = GenericArray<u8, U4>;
+    let decode = |s: &[u8; 8]| A::from_base64(GenericArray::from_slice(s));
+
+    assert_eq!(decode(b"3q2+7w=="), Ok(arr![0xDE, 0xAD, 0xBE, 0xEF]));
+    assert_eq!(
+        decode(b"3q2-7w=="),
+        Err(Base64Error::InvalidChar { index: 3 })
+    );
+    assert_eq!(
+        decode(b"3q=+7w=="),
+        Err(Base64Error::InvalidChar { index: 2 })
+    );
+    assert_eq!(
+        decode(b"3q2+7w=A"),
+        Err(Base64Error::InvalidPadding { index: 7 })
+    );
+    assert_eq!(
+        decode(b"3q2+7x=="),
+        Err(Base64Error::InvalidChar { index: 5 })
+    );
+
+    assert_eq!(
+        Base64Error::InvalidPadding { index: 6 }.to_string(),
+        "Base64Error: Expected padding at index 6"
+    );
+}
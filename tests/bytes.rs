@@ -0,0 +1,264 @@
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U1024, U16, U19, U2, U3, U32, U8};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_xor() {
+    let key = GenericArray::<u8, U16>::generate(|i| i as u8 * 17);
+    let plain = GenericArray::<u8, U16>::generate(|i| i as u8);
+
+    let mut cipher = plain;
+    cipher.xor_assign(&key);
+
+    for i in 0..16 {
+        assert_eq!(cipher[i], plain[i] ^ key[i]);
+    }
+
+    assert_eq!(cipher.xor(key), plain);
+
+    let a = GenericArray::<u8, U32>::generate(|i| i as u8);
+    assert_eq!(a.xor(a), GenericArray::<u8, U32>::default());
+
+    let mut empty = GenericArray::<u8, U0>::default();
+    empty.xor_assign(&arr![]);
+}
+
+#[test]
+fn test_as_str() {
+    let hello = GenericArray::<u8, U16>::generate(|i| b"hello, world!\0\0\0"[i]);
+
+    assert_eq!(hello.as_str(), Ok("hello, world!\0\0\0"));
+    assert_eq!(hello.as_str_trimmed(), Ok("hello, world!"));
+
+    let full = arr![b'a', b'b', b'c'];
+    assert_eq!(full.as_str(), Ok("abc"));
+    assert_eq!(full.as_str_trimmed(), Ok("abc"));
+
+    let leading_nul = arr![0, b'a'];
+    assert_eq!(leading_nul.as_str_trimmed(), Ok(""));
+
+    // truncated multi-byte sequence
+    let invalid = arr![b'a', 0xC3];
+    let err = invalid.as_str().unwrap_err();
+    assert_eq!(err.valid_up_to(), 1);
+    assert!(invalid.as_str_trimmed().is_err());
+
+    // invalid bytes after the terminator are ignored
+    assert_eq!(arr![b'o', b'k', 0, 0xC3].as_str_trimmed(), Ok("ok"));
+
+    assert_eq!(GenericArray::<u8, U0>::default().as_str_trimmed(), Ok(""));
+}
+
+#[test]
+fn test_from_str_padded() {
+    let short = GenericArray::<u8, U16>::from_str_padded("name", 0).unwrap();
+    assert_eq!(&short[..4], b"name");
+    assert!(short[4..].iter().all(|&b| b == 0));
+    assert_eq!(short.as_str_trimmed(), Ok("name"));
+
+    let exact = GenericArray::<u8, U3>::from_str_padded("abc", b' ').unwrap();
+    assert_eq!(exact, arr![b'a', b'b', b'c']);
+
+    assert!(GenericArray::<u8, U3>::from_str_padded("abcd", b' ').is_err());
+    // length is measured in bytes, not characters
+    assert!(GenericArray::<u8, U3>::from_str_padded("\u{e9}\u{e9}", b' ').is_err());
+
+    assert_eq!(
+        GenericArray::<u8, U3>::from_str_padded("", b'-').unwrap(),
+        arr![b'-'; 3]
+    );
+    assert!(GenericArray::<u8, U0>::from_str_padded("", 0).is_ok());
+}
+
+#[test]
+fn test_count_byte() {
+    let a = GenericArray::<u8, U1024>::generate(|i| (i % 7) as u8);
+
+    for needle in 0..=7u8 {
+        let naive = a.iter().filter(|&&b| b == needle).count();
+        assert_eq!(a.count_byte(needle), naive);
+    }
+
+    assert_eq!(arr![0xFFu8; 3].count_byte(0xFF), 3);
+    // every byte matching fills each 255-byte chunk without overflowing its counter
+    assert_eq!(arr![b'\n'; U1024].count_byte(b'\n'), 1024);
+    assert_eq!(GenericArray::<u8, U0>::default().count_byte(0), 0);
+}
+
+#[test]
+fn test_rotate_bits() {
+    let x = 0x0123_4567_89AB_CDEFu64;
+    let a = GenericArray::<u8, U8>::from(x.to_be_bytes());
+
+    for n in [0, 1, 4, 7, 8, 9, 13, 31, 63, 64, 65, 200] {
+        let mut left = a;
+        left.rotate_bits_left(n);
+        assert_eq!(
+            u64::from_be_bytes(left.into()),
+            x.rotate_left(n as u32),
+            "{}",
+            n
+        );
+
+        let mut right = a;
+        right.rotate_bits_right(n);
+        assert_eq!(
+            u64::from_be_bytes(right.into()),
+            x.rotate_right(n as u32),
+            "{}",
+            n
+        );
+
+        left.rotate_bits_right(n);
+        assert_eq!(left, a);
+    }
+
+    let mut single = arr![0b1001_0110u8];
+    single.rotate_bits_left(3);
+    assert_eq!(single, arr![0b1011_0100]);
+
+    let mut empty = GenericArray::<u8, U0>::default();
+    empty.rotate_bits_left(5);
+    empty.rotate_bits_right(5);
+}
+
+#[test]
+fn test_hamming_distance() {
+    let zeros = GenericArray::<u8, U32>::default();
+    let ones = GenericArray::<u8, U32>::generate(|_| 0xFF);
+
+    assert_eq!(zeros.hamming_distance(&zeros), 0);
+    assert_eq!(zeros.hamming_distance(&ones), 256);
+
+    // spans both the word-sized body and the byte remainder
+    let a = GenericArray::<u8, U19>::generate(|i| i as u8);
+    let b = GenericArray::<u8, U19>::generate(|i| (i as u8).wrapping_mul(3));
+    let naive: u32 = a.iter().zip(&b).map(|(x, y)| (x ^ y).count_ones()).sum();
+    assert_eq!(a.hamming_distance(&b), naive);
+
+    let c = arr![0b0000_0001u8, 0b1000_0000, 0b0101_0101];
+    let d = arr![0b0000_0000u8, 0b0000_0000, 0b1010_1010];
+    assert_eq!(c.hamming_distance(&d), 10);
+    assert_eq!(d.hamming_distance(&c), 10);
+
+    assert_eq!(
+        GenericArray::<u8, U0>::default().hamming_distance(&arr![]),
+        0
+    );
+}
+
+#[test]
+fn test_bits() {
+    let bytes = arr![0x01u8, 0x80];
+
+    let msb = bytes.to_bits();
+    assert_eq!(msb.iter().position(|&b| b), Some(7));
+    assert_eq!(msb.iter().rposition(|&b| b), Some(8));
+    assert_eq!(GenericArray::<u8, U2>::from_bits(&msb), bytes);
+
+    let lsb = bytes.to_bits_lsb();
+    assert_eq!(lsb.iter().position(|&b| b), Some(0));
+    assert_eq!(lsb.iter().rposition(|&b| b), Some(15));
+    assert_eq!(GenericArray::<u8, U2>::from_bits_lsb(&lsb), bytes);
+
+    let x = GenericArray::<u8, U32>::generate(|i| (i as u8).wrapping_mul(37) ^ 0x5A);
+    assert_eq!(GenericArray::<u8, U32>::from_bits(&x.to_bits()), x);
+    assert_eq!(GenericArray::<u8, U32>::from_bits_lsb(&x.to_bits_lsb()), x);
+
+    let bits = GenericArray::<bool, _>::generate(|i| i % 3 == 0);
+    let packed = GenericArray::<u8, U3>::from_bits(&bits);
+    assert_eq!(packed.to_bits(), bits);
+    assert_eq!(
+        GenericArray::<u8, U3>::from_bits_lsb(&bits).to_bits_lsb(),
+        bits
+    );
+
+    assert!(GenericArray::<u8, U0>::default().to_bits().is_empty());
+}
+
+#[test]
+fn test_rolling_hashes() {
+    fn naive(window: &[u8], base: u64, modulus: u64) -> u64 {
+        window.iter().fold(0u128, |acc, &b| {
+            (acc * base as u128 + b as u128) % modulus as u128
+        }) as u64
+    }
+
+    let text = GenericArray::<u8, U32>::generate(|i| (i as u8).wrapping_mul(151) ^ 0x5A);
+
+    for (base, modulus) in [
+        (256, 1_000_000_007),
+        (31, 97),
+        (u64::MAX, u64::MAX - 58),
+        (7, 1),
+    ] {
+        let hashes = text.rolling_hashes::<U8>(base, modulus);
+        assert_eq!(hashes.len(), 25);
+
+        for (hash, window) in hashes.iter().zip(text.windows(8)) {
+            assert_eq!(*hash, naive(window, base, modulus));
+        }
+    }
+
+    let text = arr![b'a', b'b', b'r', b'a', b'c', b'a', b'd', b'a', b'b', b'r', b'a'];
+    let hashes = text.rolling_hashes::<U3>(256, 1_000_003);
+    let needle = arr![b'b', b'r', b'a'].rolling_hashes::<U3>(256, 1_000_003)[0];
+
+    let found: Vec<usize> = hashes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &h)| h == needle)
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(found, [1, 8]);
+
+    // a window covering the whole array
+    assert_eq!(
+        text.rolling_hashes::<generic_array::typenum::U11>(3, 1000)
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "window must be non-empty")]
+fn test_rolling_hashes_non_canonical_zero() {
+    use generic_array::typenum::{UInt, UTerm, B0};
+
+    // `typenum` considers this `NonZero`, but its length is zero
+    arr![1u8, 2, 3].rolling_hashes::<UInt<UTerm, B0>>(256, 101);
+}
+
+#[test]
+fn test_ascii_case() {
+    let mixed = GenericArray::<u8, U16>::from(*b"Hello, WORLD!\xC3\xA9\xFF");
+
+    let lower = mixed.into_ascii_lowercase();
+    assert_eq!(lower.as_slice(), b"hello, world!\xC3\xA9\xFF");
+
+    let upper = mixed.into_ascii_uppercase();
+    assert_eq!(upper.as_slice(), b"HELLO, WORLD!\xC3\xA9\xFF");
+
+    let mut a = mixed;
+    a.make_ascii_lowercase();
+    assert_eq!(a, lower);
+    a.make_ascii_uppercase();
+    assert_eq!(a, upper);
+
+    // the allocating slice methods are not shadowed
+    let lower_vec: Vec<u8> = mixed.to_ascii_lowercase();
+    assert_eq!(lower_vec, lower.as_slice());
+
+    // every non-letter byte is untouched
+    let all = GenericArray::<u8, generic_array::typenum::U256>::generate(|i| i as u8);
+    for (i, (&l, &u)) in all
+        .into_ascii_lowercase()
+        .iter()
+        .zip(all.into_ascii_uppercase().iter())
+        .enumerate()
+    {
+        let b = i as u8;
+        assert_eq!(l, b.to_ascii_lowercase());
+        assert_eq!(u, b.to_ascii_uppercase());
+    }
+}
@@ -0,0 +1,424 @@
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U2, U3, U5, U6, U9};
+use generic_array::{arr, ChunksMut};
+
+#[test]
+fn test_split_at_mut_typed() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    {
+        let (head, tail) = a.split_at_mut_typed::<U2>();
+
+        assert_eq!(head, &arr![1, 2]);
+        assert_eq!(tail, &arr![3, 4, 5]);
+
+        head.iter_mut().zip(tail.iter_mut()).for_each(|(h, t)| {
+            core::mem::swap(h, t);
+        });
+        tail[2] *= 10;
+    }
+
+    assert_eq!(a, arr![3, 4, 1, 2, 50]);
+
+    let (head, tail) = a.split_at_mut_typed::<U0>();
+    assert!(head.is_empty());
+    assert_eq!(tail.len(), 5);
+
+    let (head, tail) = a.split_at_mut_typed::<U5>();
+    assert_eq!(head.len(), 5);
+    assert!(tail.is_empty());
+}
+
+#[test]
+fn test_chunks_with_remainder_mut() {
+    let mut a = arr![1, 2, 3, 4, 5, 6, 7, 8];
+
+    {
+        let (chunks, rem) = a.chunks_with_remainder_mut::<U3>();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(rem, &[7, 8]);
+
+        for (chunk, r) in chunks.zip(rem.iter_mut().rev()) {
+            chunk[1] = 0;
+            *r += chunk[0];
+        }
+    }
+
+    assert_eq!(a, arr![1, 0, 3, 4, 0, 6, 11, 9]);
+
+    let (chunks, rem) = a.chunks_with_remainder_mut::<U2>();
+    assert_eq!(chunks.len(), 4);
+    assert!(rem.is_empty());
+
+    let mut b = arr![1, 2];
+    let (mut chunks, rem) = b.chunks_with_remainder_mut::<U5>();
+    assert!(chunks.next().is_none());
+    assert_eq!(rem, &mut [1, 2]);
+}
+
+#[test]
+fn test_for_each_chunk_mut() {
+    let mut a = arr![1u8, 2, 3, 4, 5, 6];
+
+    let mut seen = 0;
+    a.for_each_chunk_mut::<U3, _>(|chunk| {
+        seen += 1;
+        chunk.reverse();
+        chunk[0] ^= 0xF0;
+    });
+
+    assert_eq!(seen, 2);
+    assert_eq!(a, arr![0xF3, 2, 1, 0xF6, 5, 4]);
+
+    a.for_each_chunk_mut::<U6, _>(|chunk| chunk.fill(0));
+    assert_eq!(a, arr![0; 6]);
+
+    let mut empty = generic_array::GenericArray::<u8, U0>::default();
+    empty.for_each_chunk_mut::<U2, _>(|_| unreachable!());
+}
+
+#[test]
+fn test_chunks_runtime() {
+    let mut a = arr![1, 2, 3, 4, 5, 6, 7];
+
+    let chunks: Vec<&[i32]> = a.chunks_runtime(3).collect();
+    assert_eq!(chunks, [&[1, 2, 3][..], &[4, 5, 6], &[7]]);
+
+    assert_eq!(a.chunks_runtime(7).count(), 1);
+    assert_eq!(a.chunks_runtime(100).next(), Some(&a[..]));
+
+    for (i, chunk) in a.chunks_runtime_mut(2).enumerate() {
+        chunk.fill(i as i32);
+    }
+    assert_eq!(a, arr![0, 0, 1, 1, 2, 2, 3]);
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    assert_eq!(empty.chunks_runtime(2).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_runtime_zero() {
+    let a = arr![1, 2, 3];
+    let _ = a.chunks_runtime(0);
+}
+
+#[test]
+fn test_split_first_last_chunk() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    let (head, tail) = a.try_split_first_chunk_typed::<U2>().unwrap();
+    assert_eq!(head, &arr![1, 2]);
+    assert_eq!(tail, &[3, 4, 5]);
+
+    let (head, tail) = a.try_split_first_chunk_typed::<U5>().unwrap();
+    assert_eq!(head, &arr![1, 2, 3, 4, 5]);
+    assert!(tail.is_empty());
+
+    assert!(a.try_split_first_chunk_typed::<U6>().is_none());
+    assert!(a.try_split_last_chunk_typed::<U6>().is_none());
+    assert!(a.try_split_first_chunk_mut_typed::<U6>().is_none());
+    assert!(a.try_split_last_chunk_mut_typed::<U6>().is_none());
+
+    let (init, last) = a.try_split_last_chunk_typed::<U3>().unwrap();
+    assert_eq!(init, &[1, 2]);
+    assert_eq!(last, &arr![3, 4, 5]);
+
+    let (head, tail) = a.try_split_first_chunk_mut_typed::<U2>().unwrap();
+    head[0] = 10;
+    tail[0] = 30;
+
+    let (init, last) = a.try_split_last_chunk_mut_typed::<U2>().unwrap();
+    init[1] = 20;
+    last[1] = 50;
+
+    assert_eq!(a, arr![10, 20, 30, 4, 50]);
+
+    let (head, tail) = a.split_first_chunk_typed::<U0>();
+    assert!(head.is_empty());
+    assert_eq!(tail.len(), 5);
+
+    let (head, tail) = a.split_first_chunk_typed::<U3>();
+    assert_eq!(head, &arr![10, 20, 30]);
+    assert_eq!(tail, &[4, 50]);
+
+    // the const-generic slice methods are not shadowed
+    assert_eq!(
+        a.split_first_chunk::<2>(),
+        Some((&[10, 20], &[30, 4, 50][..]))
+    );
+    assert_eq!(a.split_last_chunk::<6>(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_split_first_chunk_typed_non_canonical_length() {
+    use generic_array::typenum::{UInt, UTerm, B0, U1};
+    use generic_array::GenericArray;
+
+    // `typenum` considers this length greater than or equal to `U1`, but it is zero
+    let a = GenericArray::<String, UInt<UInt<UTerm, B0>, B0>>::default();
+    let _ = a.split_first_chunk_typed::<U1>();
+}
+
+#[test]
+fn test_into_array_of_chunks() {
+    let a = arr![1, 2, 3, 4, 5, 6];
+
+    let rows = a.into_array_of_chunks::<U3>();
+    assert_eq!(rows, arr![arr![1, 2, 3], arr![4, 5, 6]]);
+
+    let whole = a.into_array_of_chunks::<U6>();
+    assert_eq!(whole, arr![arr![1, 2, 3, 4, 5, 6]]);
+
+    let boxes = arr![Box::new(1), Box::new(2), Box::new(3), Box::new(4)];
+    let pairs = boxes.into_array_of_chunks::<U2>();
+    assert_eq!(*pairs[1][0], 3);
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    assert!(empty.into_array_of_chunks::<U5>().is_empty());
+}
+
+#[test]
+fn test_chunks_mut_typed() {
+    struct Blocks<'a> {
+        chunks: ChunksMut<'a, u8, U2>,
+        seen: usize,
+    }
+
+    impl Blocks<'_> {
+        fn fill_next(&mut self, value: u8) -> bool {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    chunk.fill(value);
+                    self.seen += 1;
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    let mut a = arr![0u8; 7];
+
+    {
+        let (chunks, rem) = a.chunks_mut_typed::<U2>();
+        assert_eq!(chunks.len(), 3);
+        rem[0] = 0xFF;
+
+        let mut blocks = Blocks { chunks, seen: 0 };
+
+        assert!(blocks.fill_next(1));
+        assert!(blocks.fill_next(2));
+        assert_eq!(blocks.chunks.len(), 1);
+
+        let rest = blocks.chunks.into_slice();
+        assert_eq!(rest.len(), 1);
+        rest[0][1] = 3;
+        assert_eq!(blocks.seen, 2);
+    }
+
+    assert_eq!(a, arr![1, 1, 2, 2, 0, 3, 0xFF]);
+
+    let (mut chunks, rem) = a.chunks_mut_typed::<U3>();
+    assert!(chunks.nth(1).is_some());
+    assert!(chunks.next().is_none());
+    assert!(chunks.next().is_none());
+    assert_eq!(rem, &[0xFF]);
+}
+
+#[test]
+fn test_array_windows() {
+    let a = arr![1, 2, 3, 4, 5, 6];
+
+    for expected in [2usize, 3, 6] {
+        let windows: Vec<&[i32]> = match expected {
+            2 => a.array_windows::<U2>().map(|w| w.as_slice()).collect(),
+            3 => a.array_windows::<U3>().map(|w| w.as_slice()).collect(),
+            _ => a.array_windows::<U6>().map(|w| w.as_slice()).collect(),
+        };
+
+        assert!(windows.iter().copied().eq(a.windows(expected)));
+    }
+
+    assert_eq!(a.array_windows::<U5>().len(), 2);
+    assert_eq!(
+        a.array_windows::<U5>().next_back(),
+        Some(&arr![2, 3, 4, 5, 6])
+    );
+
+    let short = arr![1, 2];
+    assert_eq!(short.array_windows::<U3>().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_array_windows_zero() {
+    let a = arr![1, 2];
+    let _ = a.array_windows::<U0>();
+}
+
+#[cfg(feature = "internals")]
+#[test]
+fn test_windows_mut_unchecked() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    let mut count = 0;
+    for w in unsafe { a.windows_mut_unchecked::<U3>() } {
+        w[2] += w[0] + w[1];
+        count += 1;
+    }
+
+    assert_eq!(count, 3);
+    assert_eq!(a, arr![1, 2, 6, 12, 23]);
+
+    let mut rev = arr![1, 2, 3];
+    for w in unsafe { rev.windows_mut_unchecked::<U2>() }.rev() {
+        w[0] += w[1];
+    }
+    assert_eq!(rev, arr![6, 5, 3]);
+
+    assert_eq!(unsafe { rev.windows_mut_unchecked::<U6>() }.len(), 0);
+}
+
+#[test]
+fn test_rchunks_with_remainder() {
+    let a = arr![1, 2, 3, 4, 5, 6, 7, 8];
+
+    let (chunks, rem) = a.rchunks_with_remainder::<U3>();
+    assert_eq!(rem, &[1, 2]);
+    assert!(chunks.map(|c| c.as_slice()).eq(a.rchunks_exact(3)));
+
+    let (chunks, rem) = a.rchunks_with_remainder::<U2>();
+    assert!(rem.is_empty());
+    assert_eq!(chunks.len(), 4);
+
+    let (mut chunks, rem) = a.rchunks_with_remainder::<U5>();
+    assert_eq!(chunks.next(), Some(&arr![4, 5, 6, 7, 8]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(rem, &[1, 2, 3]);
+    assert_eq!(rem, a.rchunks_exact(5).remainder());
+
+    let short = arr![1, 2];
+    let (mut chunks, rem) = short.rchunks_with_remainder::<U3>();
+    assert!(chunks.next().is_none());
+    assert_eq!(rem, &[1, 2]);
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    let (mut chunks, rem) = empty.rchunks_with_remainder::<U0>();
+    assert!(chunks.next().is_none());
+    assert!(rem.is_empty());
+}
+
+#[test]
+fn test_as_rchunks() {
+    let a = arr![1, 2, 3, 4, 5, 6, 7, 8];
+
+    let (rem, chunks) = a.as_rchunks::<U3>();
+    assert_eq!(rem, &[1, 2]);
+    assert_eq!(chunks, &arr![arr![3, 4, 5], arr![6, 7, 8]]);
+
+    // chunks match the reverse iterator, in the opposite order
+    let (rchunks, rrem) = a.rchunks_with_remainder::<U3>();
+    assert_eq!(rrem, rem);
+    assert!(rchunks.eq(chunks.iter().rev()));
+
+    let (rem, chunks) = a.as_rchunks::<U2>();
+    assert!(rem.is_empty());
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks[0], arr![1, 2]);
+
+    let (rem, chunks) = a.as_rchunks::<U9>();
+    assert_eq!(rem, a.as_slice());
+    assert!(chunks.is_empty());
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    let (rem, chunks) = empty.as_rchunks::<U5>();
+    assert!(rem.is_empty() && chunks.is_empty());
+}
+
+#[test]
+fn test_first_last_chunk() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    assert_eq!(a.first_chunk_typed::<U2>(), &arr![1, 2]);
+    assert_eq!(a.last_chunk_typed::<U3>(), &arr![3, 4, 5]);
+    assert_eq!(a.first_chunk_typed::<U5>(), &a);
+    assert_eq!(a.last_chunk_typed::<U5>(), &a);
+    assert!(a.first_chunk_typed::<U0>().is_empty());
+    assert!(a.last_chunk_typed::<U0>().is_empty());
+
+    a.first_chunk_mut_typed::<U2>()[1] = 20;
+    a.last_chunk_mut_typed::<U2>()[0] = 40;
+    assert_eq!(a, arr![1, 20, 3, 40, 5]);
+
+    // the const-generic slice methods are not shadowed
+    assert_eq!(a.first_chunk::<2>(), Some(&[1, 20]));
+    assert_eq!(a.last_chunk::<6>(), None);
+    a.last_chunk_mut::<1>().unwrap()[0] = 50;
+    assert_eq!(a[4], 50);
+}
+
+#[test]
+fn test_halves() {
+    let even = arr![1, 2, 3, 4, 5, 6];
+    assert_eq!(even.halves(), (&[1, 2, 3][..], &[4, 5, 6][..]));
+
+    let odd = arr![1, 2, 3, 4, 5];
+    assert_eq!(odd.halves(), (&[1, 2, 3][..], &[4, 5][..]));
+
+    let one = arr![1];
+    assert_eq!(one.halves(), (&[1][..], &[][..]));
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    assert_eq!(empty.halves(), (&[][..], &[][..]));
+}
+
+#[test]
+fn test_swap_halves() {
+    let original = arr![1, 2, 3, 4, 5, 6, 7, 8];
+
+    let mut a = original;
+    a.swap_halves();
+    assert_eq!(a, arr![5, 6, 7, 8, 1, 2, 3, 4]);
+
+    let mut rotated = original;
+    rotated.rotate_left(4);
+    assert_eq!(a, rotated);
+
+    a.swap_halves();
+    assert_eq!(a, original);
+
+    let mut pair = arr![String::from("a"), String::from("b")];
+    pair.swap_halves();
+    assert_eq!(pair, arr![String::from("b"), String::from("a")]);
+
+    let mut empty = generic_array::GenericArray::<i32, U0>::default();
+    empty.swap_halves();
+}
+
+#[test]
+fn test_split_pair() {
+    let (head, tail) = arr![1, 2, 3, 4, 5, 6].split_pair::<U2, generic_array::typenum::U4>();
+    assert_eq!(head, arr![1, 2]);
+    assert_eq!(tail, arr![3, 4, 5, 6]);
+
+    let (head, tail) = arr![1, 2, 3].split_pair::<U0, U3>();
+    assert!(head.is_empty());
+    assert_eq!(tail, arr![1, 2, 3]);
+
+    let (head, tail) = arr![1, 2, 3].split_pair::<U3, U0>();
+    assert_eq!(head, arr![1, 2, 3]);
+    assert!(tail.is_empty());
+
+    // every element is moved into exactly one half
+    let rc = std::rc::Rc::new(());
+    let a = generic_array::GenericArray::<_, U5>::generate(|_| rc.clone());
+    let (head, tail) = a.split_pair::<U2, U3>();
+    assert_eq!(std::rc::Rc::strong_count(&rc), 6);
+    drop(head);
+    assert_eq!(std::rc::Rc::strong_count(&rc), 4);
+    drop(tail);
+    assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+}
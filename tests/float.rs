@@ -0,0 +1,51 @@
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U1024};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_sum_kahan() {
+    // one large value followed by many values below its rounding threshold
+    let a = GenericArray::<f32, U1024>::generate(|i| if i == 0 { 1.0 } else { 1e-8 });
+    let exact = 1.0 + 1023.0 * 1e-8;
+
+    let naive: f32 = a.iter().sum();
+    assert_eq!(naive, 1.0);
+    assert!((a.sum_kahan() - exact).abs() < f32::EPSILON);
+
+    let b = GenericArray::<f64, U1024>::generate(|i| if i == 0 { 1e16 } else { 1.0 });
+
+    let naive: f64 = b.iter().sum();
+    assert_eq!(naive, 1e16);
+    assert_eq!(b.sum_kahan(), 1e16 + 1023.0);
+
+    assert_eq!(arr![1.5f64, 2.5, -1.0].sum_kahan(), 3.0);
+    assert_eq!(GenericArray::<f32, U0>::default().sum_kahan(), 0.0);
+}
+
+#[test]
+fn test_lerp() {
+    let a = arr![0.0f64, -2.0, 1e20, 3.0];
+    let b = arr![8.0f64, 2.0, 1.0, 3.0];
+
+    // both endpoints are exact, even for very different magnitudes
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+
+    assert_eq!(a.lerp(&b, 0.25)[..2], [2.0, -1.0]);
+    assert_eq!(a.lerp(&b, 0.5)[..2], [4.0, 0.0]);
+    assert_eq!(a.lerp(&b, 0.75)[..2], [6.0, 1.0]);
+    assert_eq!(a.lerp(&b, 0.5)[3], 3.0);
+
+    // extrapolation
+    assert_eq!(a.lerp(&b, 2.0)[..2], [16.0, 6.0]);
+    assert_eq!(a.lerp(&b, -1.0)[..2], [-8.0, -6.0]);
+
+    let c = arr![1.0f32, 2.0];
+    assert_eq!(c.lerp(&arr![3.0, 4.0], 0.5), arr![2.0, 3.0]);
+    assert_eq!(
+        GenericArray::<f32, U0>::default()
+            .lerp(&GenericArray::default(), 0.5)
+            .len(),
+        0
+    );
+}
@@ -86,6 +86,62 @@ where
     a.zip(b, |l, r| l + r).map(|x| x + 1).fold(0, |a, x| x + a)
 }
 
+/// Example function using generics to fallibly initialize any N-length sequence
+pub fn generic_try_generate<S>(limit: usize) -> Result<S::Sequence, usize>
+where
+    S: GenericSequence<usize>,
+{
+    S::try_generate(|i| if i < limit { Ok(i * 2) } else { Err(i) })
+}
+
+#[test]
+fn test_generic_try_generate() {
+    assert_eq!(
+        generic_try_generate::<GenericArray<usize, U4>>(4),
+        Ok(arr![0, 2, 4, 6])
+    );
+    assert_eq!(generic_try_generate::<GenericArray<usize, U4>>(2), Err(2));
+    assert_eq!(
+        generic_try_generate::<&GenericArray<usize, U4>>(5),
+        Ok(arr![0, 2, 4, 6])
+    );
+
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(
+            generic_try_generate::<Box<GenericArray<usize, U4>>>(4),
+            Ok(Box::new(arr![0, 2, 4, 6]))
+        );
+        assert_eq!(
+            generic_try_generate::<Box<GenericArray<usize, U4>>>(3),
+            Err(3)
+        );
+    }
+}
+
+#[test]
+fn test_try_generate_drops() {
+    use std::cell::Cell;
+
+    struct TestDrop<'a>(&'a Cell<u32>);
+
+    impl Drop for TestDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+
+    let res = GenericArray::<TestDrop, U4>::try_generate(|i| match i {
+        3 => Err(i),
+        _ => Ok(TestDrop(&drops)),
+    });
+
+    assert_eq!(res.err(), Some(3));
+    assert_eq!(drops.get(), 3);
+}
+
 #[test]
 fn test_generics() {
     generic_map(arr![1, 2, 3, 4]);
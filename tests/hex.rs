@@ -1,8 +1,9 @@
 use generic_array::arr;
+use generic_array::sequence::GenericSequence;
 use generic_array::typenum;
-use generic_array::GenericArray;
+use generic_array::{GenericArray, HexDebug, HexError};
 use std::str::from_utf8;
-use typenum::U2048;
+use typenum::{U0, U2048, U4};
 
 #[test]
 fn short_lower_hex() {
@@ -57,3 +58,99 @@ fn truncated_upper_hex() {
     assert_eq!(format!("{:.7X}", ar), "1E140A1");
     assert_eq!(format!("{:.8X}", ar), "1E140A11");
 }
+
+#[test]
+fn hex_debug() {
+    let ar = arr![0u8, 1, 0x7F, 0x80, 0xFF];
+    assert_eq!(format!("{:?}", HexDebug(&ar)), format!("{:x}", ar));
+    assert_eq!(format!("{:?}", HexDebug(&ar)), "00017f80ff");
+
+    let long = GenericArray::<u8, U2048>::default();
+    assert_eq!(format!("{:?}", HexDebug(&long)), format!("{:x}", long));
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Key<'a> {
+        id: u8,
+        bytes: HexDebug<'a, typenum::U2>,
+    }
+
+    let bytes = arr![0xABu8, 0xCD];
+    let key = Key {
+        id: 1,
+        bytes: HexDebug(&bytes),
+    };
+    assert_eq!(format!("{:?}", key), "Key { id: 1, bytes: abcd }");
+}
+
+#[test]
+fn from_hex() {
+    let ar = GenericArray::<u8, U2048>::generate(|i| i as u8);
+    assert_eq!(GenericArray::from_hex(&format!("{:x}", ar)), Ok(ar));
+    assert_eq!(GenericArray::from_hex(&format!("{:X}", ar)), Ok(ar));
+
+    type A = GenericArray<u8, U4>;
+    assert_eq!(A::from_hex("0123abCD"), Ok(arr![0x01, 0x23, 0xAB, 0xCD]));
+    assert_eq!(A::from_hex("0123abc"), Err(HexError::Length));
+    assert_eq!(A::from_hex("0123abcdef"), Err(HexError::Length));
+    assert_eq!(
+        A::from_hex("0123abcg"),
+        Err(HexError::InvalidChar { index: 7 })
+    );
+    assert_eq!(
+        A::from_hex("0x23abcd"),
+        Err(HexError::InvalidChar { index: 1 })
+    );
+    assert_eq!(
+        A::from_hex("é123abcd"),
+        Err(HexError::InvalidChar { index: 0 })
+    );
+    assert_eq!(
+        A::from_hex(" 0123abcd"),
+        Err(HexError::InvalidChar { index: 0 })
+    );
+
+    assert_eq!(GenericArray::<u8, U0>::from_hex(""), Ok(arr![0u8; U0]));
+    assert_eq!(
+        GenericArray::<u8, U0>::from_hex("00"),
+        Err(HexError::Length)
+    );
+}
+
+#[test]
+fn from_hex_lenient() {
+    type A = GenericArray<u8, U4>;
+    let expected = Ok(arr![0x01, 0x23, 0xAB, 0xCD]);
+
+    assert_eq!(A::from_hex_lenient("0123abcd"), expected);
+    assert_eq!(A::from_hex_lenient("01 23 ab cd"), expected);
+    assert_eq!(A::from_hex_lenient("\n  0123\n\tabcd  \r\n"), expected);
+    assert_eq!(A::from_hex_lenient("01\n23\nab\ncd"), expected);
+
+    // whitespace must not split the digits of a byte
+    assert_eq!(
+        A::from_hex_lenient("0 123abcd"),
+        Err(HexError::SplitByte { index: 1 })
+    );
+    assert_eq!(
+        A::from_hex_lenient("012 3abcd"),
+        Err(HexError::SplitByte { index: 3 })
+    );
+
+    assert_eq!(A::from_hex_lenient("01 23 ab"), Err(HexError::Length));
+    assert_eq!(A::from_hex_lenient("01 23 ab c"), Err(HexError::Length));
+    assert_eq!(A::from_hex_lenient("01 23 ab cd ef"), Err(HexError::Length));
+    assert_eq!(
+        A::from_hex_lenient("01-23-ab-cd"),
+        Err(HexError::InvalidChar { index: 2 })
+    );
+    assert_eq!(
+        GenericArray::<u8, U0>::from_hex_lenient(" \n "),
+        Ok(arr![0u8; U0])
+    );
+
+    assert_eq!(
+        HexError::SplitByte { index: 3 }.to_string(),
+        "HexError: Whitespace within a byte at index 3"
+    );
+}
@@ -0,0 +1,109 @@
+use generic_array::typenum::{U0, U2, U3};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_int_bytes() {
+    let a = arr![0x0102u16, 0x0304, 0x0506];
+
+    assert_eq!(a.to_le_bytes(), arr![2, 1, 4, 3, 6, 5]);
+    assert_eq!(a.to_be_bytes(), arr![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(GenericArray::<u16, U3>::from_le_bytes(&a.to_le_bytes()), a);
+    assert_eq!(GenericArray::<u16, U3>::from_be_bytes(&a.to_be_bytes()), a);
+    assert_eq!(GenericArray::<u16, U3>::from_ne_bytes(&a.to_ne_bytes()), a);
+
+    let b = arr![-1i32, 0x1234_5678];
+    assert_eq!(
+        b.to_be_bytes(),
+        arr![0xFF, 0xFF, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0x78]
+    );
+    assert_eq!(GenericArray::<i32, U2>::from_be_bytes(&b.to_be_bytes()), b);
+    assert_eq!(GenericArray::<i32, U2>::from_le_bytes(&b.to_le_bytes()), b);
+    assert_eq!(GenericArray::<i32, U2>::from_ne_bytes(&b.to_ne_bytes()), b);
+
+    let c = arr![u64::MAX - 1, 7];
+    assert_eq!(GenericArray::<u64, U2>::from_le_bytes(&c.to_le_bytes()), c);
+
+    let d = arr![i128::MIN, 1, -2];
+    assert_eq!(GenericArray::<i128, U3>::from_be_bytes(&d.to_be_bytes()), d);
+    assert_eq!(d.to_le_bytes()[16], 1);
+
+    assert_eq!(arr![1u8, 2].to_be_bytes(), arr![1, 2]);
+    assert!(GenericArray::<u32, U0>::default().to_le_bytes().is_empty());
+}
+
+#[test]
+fn test_swap_bytes() {
+    let a = arr![0x1122_3344u32, 0xAABB_CCDD, 0];
+
+    assert_eq!(a.swap_bytes(), arr![0x4433_2211, 0xDDCC_BBAA, 0]);
+    assert_eq!(a.swap_bytes().swap_bytes(), a);
+
+    let b = arr![-2i64, i64::MAX, 1 << 40];
+    assert_eq!(b.swap_bytes().swap_bytes(), b);
+
+    assert_eq!(arr![1u8, 2].swap_bytes(), arr![1, 2]);
+
+    let c = arr![0x0102u16, 0x0304];
+    assert_eq!(c.to_be().to_ne_bytes(), c.to_be_bytes());
+    assert_eq!(c.to_le().to_ne_bytes(), c.to_le_bytes());
+
+    if cfg!(target_endian = "little") {
+        assert_eq!(c.to_le(), c);
+        assert_eq!(c.to_be(), c.swap_bytes());
+    } else {
+        assert_eq!(c.to_be(), c);
+        assert_eq!(c.to_le(), c.swap_bytes());
+    }
+}
+
+#[test]
+fn test_checked_ops() {
+    let a = arr![10u8, 20, 30, 250];
+
+    assert_eq!(a.checked_add(arr![1, 2, 3, 5]), Some(arr![11, 22, 33, 255]));
+    // only the last element overflows
+    assert_eq!(a.checked_add(arr![1, 2, 3, 6]), None);
+
+    assert_eq!(a.checked_sub(arr![10, 0, 29, 250]), Some(arr![0, 20, 1, 0]));
+    assert_eq!(a.checked_sub(arr![10, 0, 29, 251]), None);
+
+    assert_eq!(
+        a.checked_mul(arr![25, 12, 8, 1]),
+        Some(arr![250, 240, 240, 250])
+    );
+    assert_eq!(a.checked_mul(arr![25, 12, 8, 2]), None);
+
+    let b = arr![i32::MIN, -1, 0];
+    assert_eq!(b.checked_sub(arr![0, i32::MAX, i32::MIN]), None);
+    assert_eq!(b.checked_mul(arr![1, i32::MIN, 5]), None);
+    assert_eq!(
+        b.checked_add(arr![i32::MAX, 1, i32::MIN]),
+        Some(arr![-1, 0, i32::MIN])
+    );
+
+    assert_eq!(
+        GenericArray::<usize, U0>::default().checked_add(GenericArray::default()),
+        Some(GenericArray::default())
+    );
+}
+
+#[test]
+fn test_sum_widening() {
+    use generic_array::sequence::GenericSequence;
+    use generic_array::typenum::U1024;
+
+    let bytes = GenericArray::<u8, U1024>::generate(|i| 200 + (i % 56) as u8);
+    let expected: u64 = (0..1024).map(|i| 200 + (i % 56) as u64).sum();
+
+    assert_eq!(
+        bytes.iter().fold(0u8, |acc, &x| acc.wrapping_add(x)),
+        expected as u8
+    );
+    assert_eq!(bytes.sum_widening(), expected);
+
+    assert_eq!(arr![u16::MAX; U3].sum_widening(), 3 * u16::MAX as u64);
+    assert_eq!(arr![u32::MAX, u32::MAX].sum_widening(), 2 * u32::MAX as u64);
+    assert_eq!(arr![u64::MAX, 1].sum_widening(), u64::MAX as u128 + 1);
+    assert_eq!(GenericArray::<u8, U0>::default().sum_widening(), 0);
+}
@@ -128,6 +128,7 @@ fn test_into_iter_flat_map() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_fold)] // testing `fold` itself
 fn test_into_iter_fold() {
     assert_eq!(arr![1, 2, 3, 4].into_iter().fold(0, |sum, x| sum + x), 10);
 
@@ -153,11 +154,11 @@ fn test_into_iter_drops() {
         }
     }
 
-    fn r(i: &Cell<usize>) -> R {
+    fn r(i: &Cell<usize>) -> R<'_> {
         R { i }
     }
 
-    fn v(i: &Cell<usize>) -> GenericArray<R, U5> {
+    fn v(i: &Cell<usize>) -> GenericArray<R<'_>, U5> {
         arr![r(i), r(i), r(i), r(i), r(i)]
     }
 
@@ -219,3 +220,109 @@ fn assert_covariance() {
     }
 }
 */
+
+#[test]
+fn test_into_iter_rev() {
+    use generic_array::functional::FunctionalSequence;
+
+    let v: Vec<_> = arr!['a', 'b', 'c'].into_iter_rev().collect();
+    assert_eq!(v, ['c', 'b', 'a']);
+
+    let i = Cell::new(0);
+    {
+        let mut iter = arr![0, 1, 2, 3, 4]
+            .map(|n| (n, Dropper(&i)))
+            .into_iter_rev();
+
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next().unwrap().0, 4);
+        assert_eq!(i.get(), 1);
+        assert_eq!(iter.next().unwrap().0, 3);
+        assert_eq!(i.get(), 2);
+        assert_eq!(iter.next_back().unwrap().0, 0);
+        assert_eq!(iter.len(), 2);
+    }
+    assert_eq!(i.get(), 5);
+}
+
+#[test]
+fn test_iter_mut_rev() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    let mut prev = 0;
+    for x in a.iter_mut_rev() {
+        *x += prev;
+        prev = *x;
+    }
+    assert_eq!(a, arr![15, 14, 12, 9, 5]);
+    assert_eq!(a.iter_mut_rev().len(), 5);
+
+    let mut seen = Vec::new();
+    for (i, x) in a.rindexed_mut() {
+        seen.push(i);
+        *x = i as i32 * 10;
+    }
+    assert_eq!(seen, [4, 3, 2, 1, 0]);
+    assert_eq!(a, arr![0, 10, 20, 30, 40]);
+
+    // indices stay attached to their elements from both ends
+    let mut iter = a.rindexed_mut();
+    assert_eq!(iter.next().map(|(i, x)| (i, *x)), Some((4, 40)));
+    assert_eq!(iter.next_back().map(|(i, x)| (i, *x)), Some((0, 0)));
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+fn test_iter_neighbors() {
+    let a = arr![1, 2, 3, 4];
+
+    let sums: Vec<_> = a
+        .iter_neighbors()
+        .map(|(prev, cur, next)| prev.unwrap_or(&0) + cur + next.unwrap_or(&0))
+        .collect();
+    assert_eq!(sums, [3, 6, 9, 7]);
+
+    assert_eq!(a.iter_neighbors().len(), 4);
+    assert_eq!(a.iter_neighbors().next_back(), Some((Some(&3), &4, None)));
+
+    let single = arr![5];
+    let all: Vec<_> = single.iter_neighbors().collect();
+    assert_eq!(all, [(None, &5, None)]);
+
+    let empty: GenericArray<i32, generic_array::typenum::U0> = GenericArray::default();
+    assert_eq!(empty.iter_neighbors().count(), 0);
+}
+
+#[test]
+fn test_into_indexed_iter() {
+    use generic_array::functional::FunctionalSequence;
+
+    let v: Vec<_> = arr!["x", "y", "z"].into_indexed_iter().collect();
+    assert_eq!(v, [(0, "x"), (1, "y"), (2, "z")]);
+
+    let i = Cell::new(0);
+    {
+        let mut iter = arr![10, 11, 12, 13, 14]
+            .map(|n| (n, Dropper(&i)))
+            .into_indexed_iter();
+
+        let (idx, (n, _)) = iter.next().unwrap();
+        assert_eq!((idx, n), (0, 10));
+        assert_eq!(i.get(), 1);
+
+        let (idx, (n, _)) = iter.nth(1).unwrap();
+        assert_eq!((idx, n), (2, 12));
+        assert_eq!(i.get(), 3);
+
+        assert_eq!(iter.len(), 2);
+    }
+    assert_eq!(i.get(), 5);
+}
+
+struct Dropper<'a>(&'a Cell<usize>);
+
+impl Drop for Dropper<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
@@ -4,7 +4,7 @@ use core::ops::{Add, Drop};
 use generic_array::arr;
 use generic_array::functional::*;
 use generic_array::sequence::*;
-use generic_array::typenum::{U0, U3, U4, U97};
+use generic_array::typenum::{U0, U2, U3, U4, U6, U97};
 use generic_array::GenericArray;
 
 #[cfg(feature = "alloc")]
@@ -163,22 +163,46 @@ fn test_zip() {
 #[test]
 #[should_panic]
 fn test_from_iter_short() {
-    use core::iter::repeat;
+    use core::iter::repeat_n;
 
-    let a: GenericArray<_, U4> = repeat(11).take(3).collect();
+    let a: GenericArray<_, U4> = repeat_n(11, 3).collect();
 
     assert_eq!(a, arr![11, 11, 11, 0]);
 }
 
 #[test]
 fn test_from_iter() {
-    use core::iter::{once, repeat};
+    use core::iter::{once, repeat_n};
 
-    let a: GenericArray<_, U4> = repeat(11).take(3).chain(once(0)).collect();
+    let a: GenericArray<_, U4> = repeat_n(11, 3).chain(once(0)).collect();
 
     assert_eq!(a, arr![11, 11, 11, 0]);
 }
 
+#[cfg(feature = "internals")]
+#[test]
+fn test_from_trusted_len_iter() {
+    let a = GenericArray::<_, U4>::from_trusted_len_iter((1..5).map(|x| x * 10));
+    assert_eq!(a, arr![10, 20, 30, 40]);
+
+    let b = GenericArray::<u8, U0>::from_trusted_len_iter(core::iter::empty());
+    assert!(b.is_empty());
+}
+
+#[cfg(feature = "internals")]
+#[test]
+#[should_panic(expected = "expected 4 items")]
+fn test_from_trusted_len_iter_short() {
+    let _ = GenericArray::<_, U4>::from_trusted_len_iter(0..3);
+}
+
+#[cfg(all(feature = "internals", debug_assertions))]
+#[test]
+#[should_panic(expected = "expected 4 items")]
+fn test_from_trusted_len_iter_long() {
+    let _ = GenericArray::<_, U4>::from_trusted_len_iter(0..5);
+}
+
 #[allow(unused)]
 #[derive(Debug, Copy, Clone)]
 enum E {
@@ -215,7 +239,7 @@ fn test_sizes() {
     assert_eq!(size_of::<Test>(), 25 + size_of::<usize>() * 2);
 
     assert_eq!(size_of_val(&arr![1u8, 2, 3]), size_of::<u8>() * 3);
-    assert_eq!(size_of_val(&arr![1u32]), size_of::<u32>() * 1);
+    assert_eq!(size_of_val(&arr![1u32]), size_of::<u32>());
     assert_eq!(size_of_val(&arr![1u64, 2, 3, 4]), size_of::<u64>() * 4);
 
     assert_eq!(size_of::<GenericArray<Test, U97>>(), size_of::<Test>() * 97);
@@ -426,6 +450,22 @@ fn test_from_array_mut() {
     assert_eq!(&mut a_copy, a_from);
 }
 
+#[test]
+fn test_reshape() {
+    let a: GenericArray<GenericArray<i32, U6>, U2> = GenericArray::generate(|r| {
+        GenericArray::generate(|c| (r * 6 + c) as i32) //
+    });
+
+    let b: GenericArray<GenericArray<i32, U4>, U3> = a.reshape();
+
+    assert_eq!(
+        b,
+        arr![arr![0, 1, 2, 3], arr![4, 5, 6, 7], arr![8, 9, 10, 11]]
+    );
+
+    assert_eq!(a.flatten(), b.flatten());
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn test_try_from_vec() {
@@ -474,6 +514,99 @@ fn test_alloc() {
     }
 }
 
+#[test]
+fn test_as_uninit() {
+    let mut a = arr![1, 2, 3, 4];
+
+    assert_eq!(unsafe { a.as_uninit()[2].assume_init() }, 3);
+
+    unsafe {
+        let uninit = a.as_uninit_mut();
+        uninit[0].write(10);
+        uninit[3].write(40);
+    }
+
+    assert_eq!(a, arr![10, 2, 3, 40]);
+
+    let mut b: GenericArray<core::mem::MaybeUninit<i32>, U3> = GenericArray::uninit();
+    for (i, slot) in b.iter_mut().enumerate() {
+        slot.write(i as i32);
+    }
+    assert_eq!(unsafe { GenericArray::assume_init(b) }, arr![0, 1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_filter() {
+    struct TestDrop<'a>(u32, &'a Cell<u32>);
+
+    impl<'a> Drop for TestDrop<'a> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let drop_counter = Cell::new(0);
+
+    let a: GenericArray<_, U4> = GenericArray::generate(|i| TestDrop(i as u32, &drop_counter));
+    let kept = a.filter(|x| x.0 % 2 == 1);
+
+    // only the rejected elements are dropped, kept elements are moved
+    assert_eq!(drop_counter.get(), 2);
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept[0].0, 1);
+    assert_eq!(kept[1].0, 3);
+
+    drop(kept);
+    assert_eq!(drop_counter.get(), 4);
+
+    let kept = arr![NoClone(1), NoClone(2), NoClone(3)].filter(|x| x.0 != 2);
+    assert_eq!(kept, [NoClone(1), NoClone(3)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_into_padded_chunks() {
+    let a = arr![1, 2, 3, 4, 5, 6];
+
+    assert_eq!(
+        a.into_padded_chunks::<U3>(0),
+        [arr![1, 2, 3], arr![4, 5, 6]]
+    );
+    assert_eq!(
+        a.into_padded_chunks::<U4>(0),
+        [arr![1, 2, 3, 4], arr![5, 6, 0, 0]]
+    );
+    assert_eq!(a.into_padded_chunks::<U97>(0).len(), 1);
+    assert!(arr![0; U0].into_padded_chunks::<U3>(0).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_concat_slice() {
+    let a = arr![1, 2, 3];
+
+    assert_eq!(a.concat_slice(&[4, 5]), [1, 2, 3, 4, 5]);
+    assert_eq!(a.concat_slice(&[]), [1, 2, 3]);
+    assert_eq!(arr![0; U0].concat_slice(&[9]), [9]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_join_with() {
+    let records = arr![arr![1u8, 2, 3], arr![4, 5, 6]];
+
+    assert_eq!(records.join_with(&[]), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        records.join_with(&[0xFF, 0xFE]),
+        [1, 2, 3, 0xFF, 0xFE, 4, 5, 6]
+    );
+
+    assert_eq!(arr![arr![7u8]].join_with(b"--"), [7]);
+    assert_eq!(arr![arr![0u8; U0]; U3].join_with(b","), *b",,");
+    assert!(arr![arr![1u8, 2]; U0].join_with(b",").is_empty());
+}
+
 #[test]
 fn test_chunks() {
     // intended usage
@@ -508,3 +641,162 @@ fn test_chunks_fail() {
     assert!(chunks.is_empty());
     assert!(rem.is_empty());
 }
+
+#[test]
+fn test_len_const() {
+    type Block = GenericArray<u8, U6>;
+
+    const DOUBLE: usize = Block::LEN * 2;
+
+    let buffer = [0u8; DOUBLE];
+    assert_eq!(buffer.len(), 12);
+
+    let words = [0u32; GenericArray::<u16, U97>::LEN];
+    assert_eq!(words.len(), 97);
+
+    assert_eq!(GenericArray::<(), U0>::LEN, 0);
+    assert_eq!(Block::LEN, Block::len());
+}
+
+#[test]
+fn test_try_from_fn_with() {
+    let mut next = 10;
+    let a = GenericArray::<i32, U4>::try_from_fn_with(&mut next, |n, i| {
+        *n += 1;
+        Ok::<_, ()>(*n * 10 + i as i32)
+    });
+    assert_eq!(a, Ok(arr![110, 121, 132, 143]));
+    assert_eq!(next, 14);
+
+    struct TestDrop<'a>(&'a Cell<u32>);
+
+    impl Drop for TestDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    for fail_at in 0..4 {
+        let drops = Cell::new(0);
+        let mut calls = 0;
+
+        let res = GenericArray::<TestDrop, U4>::try_from_fn_with(&mut calls, |calls, i| {
+            *calls += 1;
+            match i == fail_at {
+                true => Err(i),
+                false => Ok(TestDrop(&drops)),
+            }
+        });
+
+        assert_eq!(res.err(), Some(fail_at));
+        assert_eq!(calls, fail_at + 1);
+        assert_eq!(drops.get(), fail_at as u32);
+    }
+}
+
+#[test]
+fn test_from_element() {
+    static ZEROS: GenericArray<u8, U97> = GenericArray::from_element(0);
+    const PAIRS: GenericArray<(char, i32), U3> = GenericArray::from_element(('x', -1));
+
+    assert!(ZEROS.iter().all(|&x| x == 0));
+    assert_eq!(PAIRS, arr![('x', -1), ('x', -1), ('x', -1)]);
+
+    assert_eq!(GenericArray::<f32, U4>::from_element(1.5), arr![1.5; U4]);
+    assert!(GenericArray::<u8, U0>::from_element(1).is_empty());
+}
+
+#[test]
+fn test_relabel() {
+    use generic_array::typenum::{Sum, U5};
+
+    fn sum_lengths<A, B>(
+        a: GenericArray<i32, A>,
+        b: GenericArray<i32, B>,
+    ) -> GenericArray<i32, Sum<A, B>>
+    where
+        A: generic_array::ArrayLength + core::ops::Add<B>,
+        B: generic_array::ArrayLength,
+        Sum<A, B>: generic_array::ArrayLength,
+    {
+        a.concat(b)
+    }
+
+    let joined = sum_lengths(arr![1, 2], arr![3, 4, 5]);
+    let five: GenericArray<i32, U5> = joined.relabel();
+    assert_eq!(five, arr![1, 2, 3, 4, 5]);
+
+    const RELABELED: GenericArray<u8, U3> = arr![1u8, 2, 3].relabel();
+    assert_eq!(RELABELED, arr![1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_relabel_mismatch() {
+    let _: GenericArray<i32, U4> = arr![1, 2, 3].relabel();
+}
+
+#[test]
+fn test_const_array_conversions() {
+    fn round_trip<N: generic_array::ArrayLength, const M: usize>(a: [u8; M]) -> [u8; M] {
+        GenericArray::<u8, N>::from_const_array(a).into_const_array()
+    }
+
+    assert_eq!(round_trip::<U3, 3>([1, 2, 3]), [1, 2, 3]);
+    assert_eq!(round_trip::<U0, 0>([]), [0u8; 0]);
+
+    let a: [i32; 4] = arr![1, 2, 3, 4].into_const_array();
+    assert_eq!(a, [1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "cannot convert [T; 4] into GenericArray<T, N> with N = 3")]
+fn test_from_const_array_mismatch() {
+    let _ = GenericArray::<i32, U3>::from_const_array([1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "cannot convert GenericArray<T, N> with N = 3 into [T; 2]")]
+fn test_into_const_array_mismatch() {
+    let _: [i32; 2] = arr![1, 2, 3].into_const_array();
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let (a, b, c) = arr![1, 2, 3].into();
+    assert_eq!((a, b, c), (1, 2, 3));
+
+    let arr: GenericArray<char, U4> = ('w', 'x', 'y', 'z').into();
+    assert_eq!(arr, arr!['w', 'x', 'y', 'z']);
+
+    let twelve: GenericArray<u8, _> = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11).into();
+    let (first, .., last) = <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::from(twelve);
+    assert_eq!((first, last), (0, 11));
+
+    struct TestDrop<'a>(u32, &'a Cell<u32>);
+
+    impl Drop for TestDrop<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    {
+        let arr: GenericArray<TestDrop, U3> = (
+            TestDrop(0, &drops),
+            TestDrop(1, &drops),
+            TestDrop(2, &drops),
+        )
+            .into();
+        assert_eq!(drops.get(), 0);
+
+        let (x, y, z) = arr.into();
+        assert_eq!((x.0, y.0, z.0), (0, 1, 2));
+        assert_eq!(drops.get(), 0);
+
+        drop(y);
+        assert_eq!(drops.get(), 1);
+    }
+    assert_eq!(drops.get(), 3);
+}
@@ -0,0 +1,856 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use generic_array::arr;
+use generic_array::functional::FunctionalSequence;
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U1, U1024, U2, U3, U4, U5, U6, U7, U8};
+use generic_array::GenericArray;
+
+struct TestDrop<'a>(i32, &'a Cell<u32>);
+
+impl Drop for TestDrop<'_> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+fn drops<'a>(counter: &'a Cell<u32>) -> GenericArray<TestDrop<'a>, U5> {
+    GenericArray::generate(|i| TestDrop(i as i32, counter))
+}
+
+#[test]
+fn test_zip_indexed() {
+    let a = arr![1, 2, 3, 4];
+    let b = arr![4, 3, 2, 1];
+
+    assert_eq!(
+        a.zip_indexed(b, |i, l, r| (i, l * r)),
+        arr![(0, 4), (1, 6), (2, 6), (3, 4)]
+    );
+
+    let left_drops = Cell::new(0);
+    let right_drops = Cell::new(0);
+
+    let c = drops(&left_drops).zip_indexed(drops(&right_drops), |i, l, r| {
+        assert_eq!(l.0, i as i32);
+        assert_eq!(r.0, i as i32);
+        l.0 + r.0
+    });
+
+    assert_eq!(c, arr![0, 2, 4, 6, 8]);
+    assert_eq!(left_drops.get(), 5);
+    assert_eq!(right_drops.get(), 5);
+
+    let left_drops = Cell::new(0);
+    let right_drops = Cell::new(0);
+    let out_drops = Cell::new(0);
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&left_drops).zip_indexed(drops(&right_drops), |i, _, _| {
+            if i == 2 {
+                panic!();
+            }
+            TestDrop(i as i32, &out_drops)
+        })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(left_drops.get(), 5);
+    assert_eq!(right_drops.get(), 5);
+    assert_eq!(out_drops.get(), 2);
+}
+
+#[test]
+fn test_rotated_map() {
+    let a = arr![1, 2, 3, 4, 5];
+
+    for mid in 0..=5 {
+        let mut expected = a;
+        expected.rotate_left(mid);
+
+        assert_eq!(a.rotated_map(mid, |x| x * 2), expected.map(|x| x * 2));
+    }
+
+    assert_eq!(arr![0u8; U0].rotated_map(0, |x| x), arr![0u8; U0]);
+
+    for mid in 0..=5 {
+        for panic_at in 0..5 {
+            let in_drops = Cell::new(0);
+            let out_drops = Cell::new(0);
+            let mut calls = 0;
+
+            let res = catch_unwind(AssertUnwindSafe(|| {
+                drops(&in_drops).rotated_map(mid, |x| {
+                    assert_eq!(x.0, ((calls + mid) % 5) as i32);
+
+                    if calls == panic_at {
+                        panic!();
+                    }
+                    calls += 1;
+
+                    TestDrop(x.0, &out_drops)
+                })
+            }));
+
+            assert!(res.is_err());
+            assert_eq!(in_drops.get(), 5);
+            assert_eq!(out_drops.get(), panic_at as u32);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rotated_map_out_of_range() {
+    let _ = arr![1, 2, 3].rotated_map(4, |x| x);
+}
+
+#[test]
+fn test_prefix_sum() {
+    let a = arr![5, -2, 7, 0, 1, 9];
+
+    let mut expected = a;
+    for i in 1..expected.len() {
+        expected[i] += expected[i - 1];
+    }
+
+    assert_eq!(a.prefix_sum(), expected);
+    assert_eq!(arr![1.5f32].prefix_sum(), arr![1.5]);
+    assert_eq!(arr![0u8; U0].prefix_sum(), arr![0u8; U0]);
+
+    let strings = arr![String::from("a"), String::from("b"), String::from("c")];
+    assert_eq!(
+        strings.prefix_scan_by(|acc, x| acc.clone() + &x),
+        arr![String::from("a"), String::from("ab"), String::from("abc")]
+    );
+
+    let in_drops = Cell::new(0);
+    let out_drops = Cell::new(0);
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&in_drops)
+            .map(|x| TestDrop(x.0, &out_drops))
+            .prefix_scan_by(|acc, x| match x.0 {
+                3 => panic!(),
+                _ => TestDrop(acc.0 + x.0, acc.1),
+            })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(in_drops.get(), 5);
+    // 3 already built, 2 combined into the built elements, 1 panicked on and 1 unused
+    assert_eq!(out_drops.get(), 7);
+}
+
+#[test]
+fn test_prefix_sum_into() {
+    let a = arr![5, -2, 7, 0, 1, 9];
+
+    let mut out = arr![100; U6];
+    a.prefix_sum_into(&mut out);
+    assert_eq!(out, a.prefix_sum());
+
+    // reusing the buffer overwrites every element
+    arr![1, 1, 1, 1, 1, 1].prefix_sum_into(&mut out);
+    assert_eq!(out, arr![1, 2, 3, 4, 5, 6]);
+
+    // in-place accumulation through a copy
+    let mut b = arr![2.0f32, 0.5, 0.25];
+    b.clone().prefix_sum_into(&mut b);
+    assert_eq!(b, arr![2.0, 2.5, 2.75]);
+
+    let mut empty = arr![0u8; U0];
+    arr![0u8; U0].prefix_sum_into(&mut empty);
+}
+
+#[test]
+fn test_diff() {
+    let a = arr![3, 1, 4, 1, 5, 9, 2];
+    assert_eq!(a.diff(), arr![-2, 3, -3, 4, 4, -7]);
+
+    // differences invert the running totals
+    let b = arr![2, 7, 1, 8, 2, 8];
+    assert_eq!(b.prefix_sum().diff(), arr![7, 1, 8, 2, 8]);
+
+    assert_eq!(arr![0.5f64, 0.25, 1.0].diff(), arr![-0.25, 0.75]);
+    assert_eq!(arr![42u8].diff(), arr![0u8; U0]);
+}
+
+#[test]
+fn test_try_zip() {
+    let a = arr![1, 2, 3, 4];
+    let b = arr![4, 3, 2, 1];
+
+    assert_eq!(
+        a.try_zip(b, |l, r| Ok::<_, ()>(l * r)),
+        Ok(arr![4, 6, 6, 4])
+    );
+    assert_eq!(
+        a.try_zip(b, |l, r| if l < r { Ok(r - l) } else { Err(l) }),
+        Err(3)
+    );
+
+    for fail_at in 0..5 {
+        let left_drops = Cell::new(0);
+        let right_drops = Cell::new(0);
+        let out_drops = Cell::new(0);
+
+        let res = drops(&left_drops).try_zip(drops(&right_drops), |l, r| match l.0 == fail_at {
+            true => Err(r.0),
+            false => Ok(TestDrop(l.0 + r.0, &out_drops)),
+        });
+
+        assert_eq!(res.err(), Some(fail_at));
+        assert_eq!(left_drops.get(), 5);
+        assert_eq!(right_drops.get(), 5);
+        assert_eq!(out_drops.get(), fail_at as u32);
+    }
+}
+
+#[test]
+fn test_try_map_indexed() {
+    assert_eq!(
+        arr![10, 20, 30].try_map_indexed(|i, x| Ok::<_, ()>(x + i as i32)),
+        Ok(arr![10, 21, 32])
+    );
+
+    // fail at the first, a middle, and the last element
+    for fail_at in [0, 2, 4] {
+        let src_drops = Cell::new(0);
+        let out_drops = Cell::new(0);
+        let mut calls = 0;
+
+        let res = drops(&src_drops).try_map_indexed(|i, x| {
+            calls += 1;
+            assert_eq!(i as i32, x.0);
+
+            match i == fail_at {
+                true => Err(i),
+                false => Ok(TestDrop(x.0 * 10, &out_drops)),
+            }
+        });
+
+        assert_eq!(res.err(), Some(fail_at));
+        assert_eq!(calls, fail_at + 1);
+        assert_eq!(src_drops.get(), 5);
+        assert_eq!(out_drops.get(), fail_at as u32);
+    }
+
+    let src_drops = Cell::new(0);
+    let out_drops = Cell::new(0);
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&src_drops).try_map_indexed(|i, x| match i {
+            3 => panic!(),
+            _ => Ok::<_, ()>(TestDrop(x.0, &out_drops)),
+        })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(src_drops.get(), 5);
+    assert_eq!(out_drops.get(), 3);
+}
+
+#[test]
+fn test_unzip_map() {
+    let (a, b) = arr![1, 2, 3].unzip_map(|x| (x * 2, x as u8));
+    assert_eq!(a, arr![2, 4, 6]);
+    assert_eq!(b, arr![1u8, 2, 3]);
+
+    let src_drops = Cell::new(0);
+    let left_drops = Cell::new(0);
+    let right_drops = Cell::new(0);
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&src_drops).unzip_map(|x| {
+            if x.0 == 3 {
+                panic!();
+            }
+            (TestDrop(x.0, &left_drops), TestDrop(x.0, &right_drops))
+        })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(src_drops.get(), 5);
+    assert_eq!(left_drops.get(), 3);
+    assert_eq!(right_drops.get(), 3);
+
+    let (a, b) = GenericArray::<i32, U0>::default().unzip_map(|x| (x, x));
+    assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+fn test_set() {
+    let counter = Cell::new(0);
+    let mut a = drops(&counter);
+
+    let old = a.set(4, TestDrop(40, &counter)).ok().unwrap();
+    assert_eq!(old.0, 4);
+    assert_eq!(counter.get(), 0);
+    drop(old);
+    assert_eq!(counter.get(), 1);
+
+    let (i, rejected) = a.set(5, TestDrop(50, &counter)).err().unwrap();
+    assert_eq!(i, 5);
+    assert_eq!(rejected.0, 50);
+    assert_eq!(counter.get(), 1);
+
+    assert_eq!(a[4].0, 40);
+    assert_eq!(GenericArray::<i32, U0>::default().set(0, 1), Err((0, 1)));
+}
+
+#[test]
+fn test_with() {
+    let counter = Cell::new(0);
+
+    // `TestDrop` is not `Clone`, so the remaining elements can only have been moved
+    let a = drops(&counter).with(2, TestDrop(20, &counter));
+    assert_eq!(counter.get(), 1);
+    assert!(a.iter().map(|x| x.0).eq([0, 1, 20, 3, 4]));
+
+    let a = a
+        .with(0, TestDrop(-1, &counter))
+        .with(0, TestDrop(-2, &counter));
+    assert_eq!(counter.get(), 3);
+    assert_eq!(a[0].0, -2);
+
+    drop(a);
+    assert_eq!(counter.get(), 8);
+
+    let counter = Cell::new(0);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&counter).with(5, TestDrop(50, &counter))
+    }));
+    assert!(res.is_err());
+    assert_eq!(counter.get(), 6);
+}
+
+#[test]
+fn test_copy_within() {
+    let mut a = arr![0, 1, 2, 3, 4, 5];
+    a.copy_within(0..4, 2);
+    assert_eq!(a, arr![0, 1, 0, 1, 2, 3]);
+
+    let mut a = arr![0, 1, 2, 3, 4, 5];
+    a.copy_within(2.., 0);
+    assert_eq!(a, arr![2, 3, 4, 5, 4, 5]);
+
+    let mut a = arr![0, 1, 2, 3, 4, 5];
+    a.copy_within(..=1, 4);
+    assert_eq!(a, arr![0, 1, 2, 3, 0, 1]);
+
+    a.copy_within(3..3, 6);
+    assert_eq!(a, arr![0, 1, 2, 3, 0, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_within_out_of_bounds() {
+    let mut a = arr![0, 1, 2, 3];
+    a.copy_within(1..3, 3);
+}
+
+#[test]
+fn test_reduce() {
+    assert_eq!(arr![1, 2, 3].reduce(|a, b| a - b), Some(-4));
+    assert_eq!(arr![7].reduce(|_, _| unreachable!()), Some(7));
+    assert_eq!(
+        GenericArray::<i32, U0>::default().reduce(|a, b| a + b),
+        None
+    );
+    assert_eq!(arr![1, 5, 2].reduce_nonempty(i32::max), 5);
+
+    let counter = Cell::new(0);
+    let total = drops(&counter).reduce_nonempty(|a, b| TestDrop(a.0 + b.0, a.1));
+    assert_eq!(total.0, 10);
+    assert_eq!(counter.get(), 8);
+    drop(total);
+    assert_eq!(counter.get(), 9);
+
+    let counter = Cell::new(0);
+    let mut calls = 0;
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&counter).reduce(|a, _| {
+            calls += 1;
+            if calls == 3 {
+                panic!();
+            }
+            a
+        })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+#[should_panic(expected = "array is non-empty")]
+fn test_reduce_nonempty_non_canonical_zero() {
+    use generic_array::typenum::{UInt, UTerm, B0};
+
+    // `typenum` considers this `NonZero`, but its length is zero
+    GenericArray::<String, UInt<UTerm, B0>>::default().reduce_nonempty(|a, _| a);
+}
+
+#[test]
+fn test_transpose_options() {
+    assert_eq!(
+        arr![Some('a'), Some('b')].transpose_options(),
+        Some(arr!['a', 'b'])
+    );
+    assert_eq!(arr![None, Some('b')].transpose_options(), None);
+    assert_eq!(
+        GenericArray::<Option<i32>, U0>::default().transpose_options(),
+        Some(arr![])
+    );
+
+    let counter = Cell::new(0);
+    let all = drops(&counter).map(Some).transpose_options().unwrap();
+    assert_eq!(counter.get(), 0);
+    drop(all);
+    assert_eq!(counter.get(), 5);
+
+    let counter = Cell::new(0);
+    let some_missing = drops(&counter).map(|x| if x.0 == 2 { None } else { Some(x) });
+    assert_eq!(counter.get(), 1);
+    assert!(some_missing.transpose_options().is_none());
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+fn test_transpose_results() {
+    assert_eq!(
+        arr![Ok::<_, ()>(1), Ok(2)].transpose_results(),
+        Ok(arr![1, 2])
+    );
+    assert_eq!(arr![Err(1), Ok(2), Err(3)].transpose_results(), Err(1));
+
+    let counter = Cell::new(0);
+    {
+        let a: GenericArray<Result<TestDrop, &str>, U5> = arr![
+            Ok(TestDrop(0, &counter)),
+            Ok(TestDrop(1, &counter)),
+            Err("bad"),
+            Err("worse"),
+            Err("worst")
+        ];
+
+        assert_eq!(a.transpose_results().err(), Some("bad"));
+        assert_eq!(counter.get(), 2);
+    }
+    assert_eq!(counter.get(), 2);
+
+    let counter = Cell::new(0);
+    let all = drops(&counter).map(Ok::<_, ()>).transpose_results();
+    assert_eq!(counter.get(), 0);
+    drop(all);
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+fn test_clamp_each() {
+    let a = arr![i32::MIN, -1, 0, 1, 99, 100, 101, i32::MAX];
+
+    assert_eq!(a.clamp_each(0, 100), arr![0, 0, 0, 1, 99, 100, 100, 100]);
+    assert_eq!(a.clamp_each(7, 7), arr![7; 8]);
+
+    let mut b = a;
+    b.clamp_each_mut(-1, 1);
+    assert_eq!(b, arr![-1, -1, 0, 1, 1, 1, 1, 1]);
+
+    let f = arr![f32::NEG_INFINITY, -0.0, 0.5, 1.0, 2.0];
+    assert_eq!(
+        f.clamp_each_by(0.0, 1.0, |x, y| x.partial_cmp(y).unwrap()),
+        arr![0.0, -0.0, 0.5, 1.0, 1.0]
+    );
+
+    let words = arr!["apple", "kiwi", "zebra"];
+    assert_eq!(
+        words.clamp_each_by("banana", "orange", |x, y| x.cmp(y)),
+        arr!["banana", "kiwi", "orange"]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_each_invalid_bounds() {
+    let _ = arr![1, 2, 3].clamp_each(3, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_each_by_invalid_bounds() {
+    let _ = arr![1.0, 2.0].clamp_each_by(1.0, 0.0, f64::total_cmp);
+}
+
+#[test]
+fn test_window_sums() {
+    let a = GenericArray::<u32, U8>::generate(|i| (i as u32 * 7919) % 13);
+
+    let naive: Vec<u32> = a.windows(3).map(|w| w.iter().sum()).collect();
+    assert_eq!(a.window_sums::<U3>().as_slice(), &naive[..]);
+
+    assert_eq!(a.window_sums::<U1>(), a);
+    assert_eq!(a.window_sums::<U8>(), arr![a.iter().sum::<u32>()]);
+
+    let f = arr![0.5f64, 1.5, -2.0, 4.0, 0.25];
+    assert_eq!(f.window_sums::<U5>(), arr![4.25]);
+    assert_eq!(f.window_sums::<U3>(), arr![0.0, 3.5, 2.25]);
+}
+
+#[test]
+#[should_panic(expected = "empty window in GenericArray::window_sums")]
+fn test_window_sums_non_canonical_zero() {
+    use generic_array::typenum::{UInt, UTerm, B0};
+
+    // `typenum` considers this `NonZero`, but its length is zero
+    arr![1, 2, 3].window_sums::<UInt<UTerm, B0>>();
+}
+
+#[test]
+fn test_try_concat_to() {
+    let prefix = arr![String::from("a"), String::from("b")];
+
+    let full = prefix.try_concat_to::<U5>(&["c".into(), "d".into(), "e".into()]);
+    assert_eq!(full, Some(arr!["a", "b", "c", "d", "e"].map(String::from)));
+
+    assert_eq!(prefix.try_concat_to::<U5>(&["c".into()]), None);
+    assert_eq!(prefix.try_concat_to::<U1>(&[]), None);
+
+    let same = arr![1, 2, 3].try_concat_to::<U3>(&[]);
+    assert_eq!(same, Some(arr![1, 2, 3]));
+
+    let empty = GenericArray::<i32, U0>::default();
+    assert_eq!(empty.try_concat_to::<U1>(&[7]), Some(arr![7]));
+}
+
+#[test]
+fn test_resize() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let tracked = || GenericArray::<Option<Rc<()>>, U5>::generate(|_| Some(rc.clone()));
+
+    let shrunk = tracked().resize::<U2>();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(shrunk);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let grown = tracked().resize::<U8>();
+    assert_eq!(Rc::strong_count(&rc), 6);
+    assert_eq!(grown.iter().filter(|x| x.is_none()).count(), 3);
+    assert!(grown[..5].iter().all(Option::is_some));
+    drop(grown);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let same = tracked().resize::<U5>();
+    assert_eq!(Rc::strong_count(&rc), 6);
+    drop(same);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    assert_eq!(arr![1, 2, 3].resize::<U5>(), arr![1, 2, 3, 0, 0]);
+    assert!(arr![1, 2].resize::<U0>().is_empty());
+}
+
+#[test]
+fn test_convolve() {
+    let signal = arr![3, -1, 4, 1, 5, 9, 2, 6];
+    let kernel = arr![1, 2, 3];
+
+    let naive: Vec<i32> = signal
+        .windows(3)
+        .map(|w| w[0] * 3 + w[1] * 2 + w[2])
+        .collect();
+    assert_eq!(signal.convolve(&kernel).as_slice(), &naive[..]);
+
+    assert_eq!(signal.convolve(&arr![2]), signal.map(|x| x * 2));
+
+    let full = arr![1, 1, 1, 1, 1, 1, 1, 2];
+    assert_eq!(signal.convolve(&full), arr![2 * 3 + 26]);
+
+    let smooth = arr![0.0f64, 4.0, 8.0, 4.0].convolve(&arr![0.5, 0.5]);
+    assert_eq!(smooth, arr![2.0, 6.0, 6.0]);
+}
+
+#[test]
+#[should_panic(expected = "empty kernel in GenericArray::convolve")]
+fn test_convolve_non_canonical_zero() {
+    use generic_array::typenum::{UInt, UTerm, B0};
+
+    // `typenum` considers this `NonZero`, but its length is zero
+    let kernel = GenericArray::<i32, UInt<UTerm, B0>>::default();
+    arr![1, 2, 3].convolve(&kernel);
+}
+
+#[test]
+fn test_map_ok() {
+    let a: GenericArray<Result<i32, String>, U3> = arr![
+        Ok(1),
+        Err(String::from("first")),
+        Err(String::from("second"))
+    ];
+
+    let mut calls = 0;
+    let b = a.map_ok(|x| {
+        calls += 1;
+        x.to_string()
+    });
+
+    assert_eq!(calls, 1);
+    assert_eq!(
+        b,
+        arr![Ok("1".into()), Err("first".into()), Err("second".into())]
+    );
+
+    let all_ok = arr![Ok::<_, ()>(1u8), Ok(2)].map_ok(u16::from);
+    assert_eq!(all_ok.transpose_results(), Ok(arr![1u16, 2]));
+}
+
+#[test]
+fn test_try_rotate() {
+    let mut a = arr![1, 2, 3, 4, 5];
+
+    assert_eq!(a.try_rotate_left(0), Ok(()));
+    assert_eq!(a, arr![1, 2, 3, 4, 5]);
+    assert_eq!(a.try_rotate_left(5), Ok(()));
+    assert_eq!(a, arr![1, 2, 3, 4, 5]);
+    assert_eq!(a.try_rotate_left(2), Ok(()));
+    assert_eq!(a, arr![3, 4, 5, 1, 2]);
+    assert_eq!(a.try_rotate_left(6), Err(6));
+    assert_eq!(a.try_rotate_left(usize::MAX), Err(usize::MAX));
+    assert_eq!(a, arr![3, 4, 5, 1, 2]);
+
+    assert_eq!(a.try_rotate_right(2), Ok(()));
+    assert_eq!(a, arr![1, 2, 3, 4, 5]);
+    assert_eq!(a.try_rotate_right(5), Ok(()));
+    assert_eq!(a.try_rotate_right(6), Err(6));
+    assert_eq!(a, arr![1, 2, 3, 4, 5]);
+
+    let mut empty = GenericArray::<i32, U0>::default();
+    assert_eq!(empty.try_rotate_left(0), Ok(()));
+    assert_eq!(empty.try_rotate_right(1), Err(1));
+}
+
+#[test]
+fn test_rotate_left_by() {
+    let a = arr![1, 2, 3, 4, 5];
+
+    fn check<K>(a: GenericArray<i32, U5>)
+    where
+        K: generic_array::ArrayLength
+            + generic_array::typenum::IsLessOrEqual<U5, Output = generic_array::typenum::True>,
+    {
+        let mut expected = a;
+        expected.rotate_left(K::USIZE);
+        assert_eq!(a.rotate_left_by::<K>(), expected);
+    }
+
+    check::<U0>(a);
+    check::<U1>(a);
+    check::<U2>(a);
+    check::<U3>(a);
+    check::<U5>(a);
+
+    // move-only elements
+    let counter = Cell::new(0);
+    let rotated = drops(&counter).rotate_left_by::<U3>();
+    assert!(rotated.iter().map(|x| x.0).eq([3, 4, 0, 1, 2]));
+    assert_eq!(counter.get(), 0);
+    drop(rotated);
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+fn test_tree_reduce() {
+    let a = GenericArray::<i64, U7>::generate(|i| i as i64 * 3 - 4);
+    assert_eq!(a.tree_reduce(|l, r| l + r), a.reduce(|l, r| l + r));
+
+    let order = GenericArray::<String, U5>::generate(|i| i.to_string());
+    assert_eq!(
+        order.tree_reduce(|l, r| format!("({l}{r})")).unwrap(),
+        "(((01)(23))4)"
+    );
+
+    assert_eq!(arr![9].tree_reduce(|_, _| unreachable!()), Some(9));
+    assert_eq!(
+        GenericArray::<i32, U0>::default().tree_reduce(|l, r| l + r),
+        None
+    );
+
+    let tenths = GenericArray::<f32, U1024>::generate(|_| 0.1);
+    let exact = 102.4f64;
+    let naive_err = (tenths.reduce(|l, r| l + r).unwrap() as f64 - exact).abs();
+    let tree_err = (tenths.tree_reduce(|l, r| l + r).unwrap() as f64 - exact).abs();
+    assert!(tree_err < naive_err, "{} >= {}", tree_err, naive_err);
+
+    let counter = Cell::new(0);
+    let total = drops(&counter).tree_reduce(|l, r| TestDrop(l.0 + r.0, l.1));
+    assert_eq!(total.as_ref().map(|t| t.0), Some(10));
+    assert_eq!(counter.get(), 8);
+
+    let counter = Cell::new(0);
+    let mut calls = 0;
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        drops(&counter).tree_reduce(|l, r| {
+            calls += 1;
+            if calls == 3 {
+                panic!();
+            }
+            TestDrop(l.0 + r.0, l.1)
+        })
+    }));
+
+    assert!(res.is_err());
+    // 5 originals, plus 2 intermediate results
+    assert_eq!(counter.get(), 7);
+}
+
+#[test]
+fn test_dedup_in_place() {
+    let mut a = arr![3, 3, 1, 1, 1, 2, 3, 3];
+    assert_eq!(a.count_distinct(), 4);
+
+    let len = a.dedup_in_place();
+    assert_eq!(len, 4);
+    assert_eq!(a[..len], [3, 1, 2, 3]);
+
+    // the tail holds exactly the removed duplicates
+    let mut tail = a[len..].to_vec();
+    tail.sort();
+    assert_eq!(tail, [1, 1, 3, 3]);
+
+    let mut distinct = arr![1, 2, 3];
+    assert_eq!(distinct.count_distinct(), 3);
+    assert_eq!(distinct.dedup_in_place(), 3);
+    assert_eq!(distinct, arr![1, 2, 3]);
+
+    let mut same = arr![String::from("x"), String::from("x"), String::from("x")];
+    assert_eq!(same.count_distinct(), 1);
+    assert_eq!(same.dedup_in_place(), 1);
+    assert!(same.iter().all(|s| s == "x"));
+
+    let mut empty = arr![0u8; U0];
+    assert_eq!(empty.count_distinct(), 0);
+    assert_eq!(empty.dedup_in_place(), 0);
+}
+
+#[test]
+fn test_histogram() {
+    let a = GenericArray::<u16, U1024>::generate(|i| (i * 37 % 1000) as u16);
+    let bins = a.histogram::<U5>(|&x| x as usize / 200);
+
+    assert_eq!(bins.iter().sum::<u32>(), 1024);
+    assert_eq!(bins, arr![206, 205, 206, 205, 202]);
+
+    let parity = arr![1, 2, 3, 4, 5, 7].histogram::<U2>(|x| x % 2);
+    assert_eq!(parity, arr![2, 4]);
+
+    assert_eq!(arr![0u8; U0].histogram::<U3>(|_| 7), arr![0, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "bin index 2 is out of range for 2 bins")]
+fn test_histogram_out_of_range() {
+    arr![0, 1, 2].histogram::<U2>(|&x| x);
+}
+
+#[test]
+fn test_gather() {
+    let source = [1.5f32, 2.5, 3.5, 4.5];
+    let a = GenericArray::gather(&source, &arr![3, 3, 0, 1, 2]);
+    assert_eq!(a, arr![4.5, 4.5, 1.5, 2.5, 3.5]);
+
+    let strings = [String::from("x"), String::from("y")];
+    assert_eq!(
+        GenericArray::checked_gather(&strings, &arr![1, 0, 1]),
+        Some(arr![
+            String::from("y"),
+            String::from("x"),
+            String::from("y")
+        ])
+    );
+    assert_eq!(GenericArray::checked_gather(&strings, &arr![0, 2]), None);
+
+    let empty: [u8; 0] = [];
+    assert_eq!(
+        GenericArray::checked_gather(&empty, &arr![0usize; U0]),
+        Some(arr![0u8; U0])
+    );
+    assert_eq!(GenericArray::checked_gather(&empty, &arr![0]), None);
+
+    let res = catch_unwind(|| GenericArray::gather(&[1, 2, 3], &arr![0, 1, 3]));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_permute_inverse() {
+    let source = ["a", "b", "c", "d"].map(String::from);
+    let x = GenericArray::from_array(source.clone());
+    let mut valid = 0;
+
+    // every array of indices in 0..4, of which 4! = 24 are permutations
+    for n in 0..256 {
+        let p = GenericArray::<usize, U4>::generate(|i| (n >> (2 * i)) & 3);
+        let permuted = GenericArray::gather(&source, &p);
+
+        match permuted.clone().checked_permute_inverse(p) {
+            Ok(restored) => {
+                assert_eq!(restored, x);
+                assert_eq!(permuted.permute_inverse(p), x);
+                valid += 1;
+            }
+            Err(unchanged) => assert_eq!(unchanged, permuted),
+        }
+    }
+
+    assert_eq!(valid, 24);
+
+    let counter = Cell::new(0);
+    let a = drops(&counter).permute_inverse(arr![4, 3, 2, 1, 0]);
+    assert_eq!(a.map(|x| x.0), arr![4, 3, 2, 1, 0]);
+    assert_eq!(counter.get(), 5);
+
+    let counter = Cell::new(0);
+    assert!(drops(&counter)
+        .checked_permute_inverse(arr![0, 1, 2, 3, 3])
+        .is_err());
+    assert_eq!(counter.get(), 5);
+
+    assert_eq!(
+        arr![0u8; U0].permute_inverse(arr![0usize; U0]),
+        arr![0u8; U0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "indices are not a permutation")]
+fn test_permute_inverse_invalid() {
+    arr![1, 2, 3].permute_inverse(arr![0, 2, 2]);
+}
+
+#[test]
+fn test_comparison_masks() {
+    let a = arr![5, -1, 3, 3, 0];
+    let b = arr![4, 2, 3, 7, 0];
+
+    assert_eq!(a.eq_mask(&b), arr![false, false, true, false, true]);
+    assert_eq!(a.lt_mask(&b), arr![false, true, false, true, false]);
+    assert_eq!(a.gt_mask(&b), arr![true, false, false, false, false]);
+
+    // element-wise maximum, selecting from either array by the mask
+    let max = a
+        .gt_mask(&b)
+        .zip(a.zip(b, |x, y| (x, y)), |m, (x, y)| if m { x } else { y });
+    assert_eq!(max, arr![5, 2, 3, 7, 0]);
+
+    let f = arr![1.0, f64::NAN, 2.0];
+    let g = arr![1.0, f64::NAN, 1.0];
+    assert_eq!(f.eq_mask(&g), arr![true, false, false]);
+    assert_eq!(f.lt_mask(&g), arr![false, false, false]);
+    assert_eq!(f.gt_mask(&g), arr![false, false, true]);
+
+    assert!(arr![0u8; U0].eq_mask(&arr![0u8; U0]).is_empty());
+}
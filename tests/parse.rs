@@ -0,0 +1,86 @@
+use std::cell::Cell;
+use std::str::FromStr;
+
+use generic_array::typenum::{U0, U3};
+use generic_array::{arr, GenericArray, ParseError};
+
+#[test]
+fn test_parse_delimited() {
+    assert_eq!(
+        GenericArray::<i32, U3>::parse_delimited("-1;0;1", ';'),
+        Ok(arr![-1, 0, 1])
+    );
+
+    assert_eq!(
+        GenericArray::<i32, U3>::parse_delimited("1;2", ';'),
+        Err(ParseError::Length)
+    );
+    assert_eq!(
+        GenericArray::<i32, U3>::parse_delimited("1;2;3;4", ';'),
+        Err(ParseError::Length)
+    );
+    assert_eq!(
+        GenericArray::<i32, U3>::parse_delimited("1;2;3;", ';'),
+        Err(ParseError::Length)
+    );
+
+    let err = GenericArray::<i32, U3>::parse_delimited("1; 2;3", ';').unwrap_err();
+    assert!(matches!(err, ParseError::Element { index: 1, .. }));
+    assert_eq!(
+        err.to_string(),
+        "ParseError: Invalid value at index 1: invalid digit found in string"
+    );
+
+    assert_eq!(
+        GenericArray::<i32, U0>::parse_delimited("", ','),
+        Ok(arr![])
+    );
+    assert_eq!(
+        GenericArray::<i32, U0>::parse_delimited("1", ','),
+        Err(ParseError::Length)
+    );
+}
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug)]
+struct Counted;
+
+impl FromStr for Counted {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "ok" => Ok(Counted),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.with(|d| d.set(d.get() + 1));
+    }
+}
+
+#[test]
+fn test_parse_delimited_drops() {
+    let res = GenericArray::<Counted, U3>::parse_delimited("ok,ok,no", ',');
+    assert!(matches!(
+        res,
+        Err(ParseError::Element {
+            index: 2,
+            error: ()
+        })
+    ));
+    assert_eq!(DROPS.with(Cell::get), 2);
+
+    let res = GenericArray::<Counted, U3>::parse_delimited("ok,ok,ok,ok", ',');
+    assert!(matches!(res, Err(ParseError::Length)));
+    assert_eq!(DROPS.with(Cell::get), 5);
+
+    drop(GenericArray::<Counted, U3>::parse_delimited("ok,ok,ok", ',').unwrap());
+    assert_eq!(DROPS.with(Cell::get), 8);
+}
@@ -0,0 +1,128 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U1000};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_index_of() {
+    let a = arr!['a', 'b', 'c', 'b', 'a'];
+
+    assert_eq!(a.index_of(&'a'), Some(0));
+    assert_eq!(a.index_of(&'b'), Some(1));
+    assert_eq!(a.index_of(&'c'), Some(2));
+    assert_eq!(a.index_of(&'d'), None);
+
+    assert_eq!(a.last_index_of(&'a'), Some(4));
+    assert_eq!(a.last_index_of(&'b'), Some(3));
+    assert_eq!(a.last_index_of(&'c'), Some(2));
+    assert_eq!(a.last_index_of(&'d'), None);
+
+    assert_eq!(arr![0; U0].index_of(&0), None);
+    assert_eq!(arr![0; U0].last_index_of(&0), None);
+}
+
+#[test]
+fn test_position_max_min_by_key() {
+    let a = arr![(2, 'a'), (5, 'b'), (1, 'c'), (5, 'd'), (1, 'e')];
+
+    // ties resolve to the last maximum and the first minimum
+    assert_eq!(a.position_max_by_key(|x| x.0), Some(3));
+    assert_eq!(a.position_min_by_key(|x| x.0), Some(2));
+
+    assert_eq!(a.position_max_by_key(|x| x.1), Some(4));
+    assert_eq!(a.position_min_by_key(|x| x.1), Some(0));
+
+    let same = arr![7, 7, 7];
+    assert_eq!(same.position_max_by_key(|&x| x), Some(2));
+    assert_eq!(same.position_min_by_key(|&x| x), Some(0));
+
+    let empty = generic_array::GenericArray::<i32, U0>::default();
+    assert_eq!(empty.position_max_by_key(|&x| x), None);
+    assert_eq!(empty.position_min_by_key(|&x| x), None);
+}
+
+#[test]
+fn test_min_max() {
+    let a = arr![4, 8, 1, 9, 9, 1, 5];
+
+    assert_eq!(
+        a.min_max(),
+        Some((a.iter().min().unwrap(), a.iter().max().unwrap()))
+    );
+    assert_eq!(arr![7].min_max(), Some((&7, &7)));
+    assert_eq!(arr![0; U0].min_max(), None);
+
+    // ties resolve to the first minimum and the last maximum, for both even and odd lengths
+    let b = arr![(2, 'a'), (5, 'b'), (1, 'c'), (5, 'd'), (1, 'e'), (2, 'f')];
+    let (min, max) = b.min_max_by_key(|x| x.0).unwrap();
+    assert_eq!((min.1, max.1), ('c', 'd'));
+
+    let c = arr![(2, 'a'), (5, 'b'), (1, 'c'), (5, 'd'), (1, 'e')];
+    let (min, max) = c.min_max_by_key(|x| x.0).unwrap();
+    assert_eq!((min.1, max.1), ('c', 'd'));
+
+    let same = arr![(0, 'a'), (0, 'b'), (0, 'c'), (0, 'd')];
+    let (min, max) = same.min_max_by_key(|x| x.0).unwrap();
+    assert_eq!((min.1, max.1), ('a', 'd'));
+
+    let mut calls = 0;
+    assert_eq!(
+        a.min_max_by_key(|x: &i32| {
+            calls += 1;
+            -x
+        }),
+        Some((&9, &1))
+    );
+    assert_eq!(calls, 7);
+}
+
+#[test]
+fn test_min_max_comparisons() {
+    struct Counted<'a>(u32, &'a Cell<usize>);
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Counted<'_> {}
+
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.1.set(self.1.get() + 1);
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let count = Cell::new(0);
+    let a = GenericArray::<Counted, U1000>::generate(|i| Counted((i as u32 * 7919) % 1000, &count));
+
+    let (min, max) = a.min_max().unwrap();
+    assert_eq!((min.0, max.0), (0, 999));
+    assert!(count.get() <= 1500, "{} comparisons", count.get());
+}
+
+#[test]
+fn test_nth_from_end() {
+    let mut a = arr!['a', 'b', 'c', 'd'];
+
+    assert_eq!(a.nth_from_end(0), Some(&'d'));
+    assert_eq!(a.nth_from_end(3), Some(&'a'));
+    assert_eq!(a.nth_from_end(4), None);
+    assert_eq!(a.nth_from_end(usize::MAX), None);
+
+    *a.nth_from_end_mut(1).unwrap() = 'x';
+    assert_eq!(a, arr!['a', 'b', 'x', 'd']);
+    assert_eq!(a.nth_from_end_mut(4), None);
+
+    assert_eq!(arr![0; U0].nth_from_end(0), None);
+}
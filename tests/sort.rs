@@ -0,0 +1,212 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U64};
+use generic_array::{arr, GenericArray};
+
+struct Keyed<'a>(u32, char, &'a Cell<u32>);
+
+impl Drop for Keyed<'_> {
+    fn drop(&mut self) {
+        self.2.set(self.2.get() + 1);
+    }
+}
+
+#[test]
+fn test_merge() {
+    assert_eq!(
+        arr![1, 4, 9].merge(arr![2, 3, 10, 11]),
+        arr![1, 2, 3, 4, 9, 10, 11]
+    );
+    assert_eq!(arr![5, 6].merge(arr![1, 2]), arr![1, 2, 5, 6]);
+    assert_eq!(
+        arr![1, 2].merge(GenericArray::<i32, U0>::default()),
+        arr![1, 2]
+    );
+    assert_eq!(GenericArray::<i32, U0>::default().merge(arr![3]), arr![3]);
+}
+
+#[test]
+fn test_merge_by_stable_move_only() {
+    let drops = Cell::new(0);
+
+    let a = arr![
+        Keyed(1, 'a', &drops),
+        Keyed(2, 'a', &drops),
+        Keyed(2, 'a', &drops)
+    ];
+    let b = arr![
+        Keyed(0, 'b', &drops),
+        Keyed(2, 'b', &drops),
+        Keyed(3, 'b', &drops)
+    ];
+
+    let merged = a.merge_by(b, |x, y| x.0.cmp(&y.0));
+
+    assert_eq!(drops.get(), 0);
+
+    let order: Vec<_> = merged.iter().map(|k| (k.0, k.1)).collect();
+    assert_eq!(
+        order,
+        [(0, 'b'), (1, 'a'), (2, 'a'), (2, 'a'), (2, 'b'), (3, 'b')]
+    );
+
+    drop(merged);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_merge_by_panic() {
+    let drops = Cell::new(0);
+    let mut comparisons = 0;
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let a = arr![Keyed(1, 'a', &drops), Keyed(3, 'a', &drops)];
+        let b = arr![Keyed(2, 'b', &drops), Keyed(4, 'b', &drops)];
+
+        a.merge_by(b, |x, y| {
+            comparisons += 1;
+            if comparisons == 3 {
+                panic!();
+            }
+            x.0.cmp(&y.0)
+        })
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_try_sort_by() {
+    let mut a = GenericArray::<u32, U64>::generate(|i| (i as u32 * 7919) % 61);
+    let mut expected = a;
+    expected.sort();
+
+    assert_eq!(a.try_sort_by(|x, y| Ok::<_, ()>(x.cmp(y))), Ok(()));
+    assert_eq!(a, expected);
+
+    // stability
+    let mut b = arr![(2, 'a'), (1, 'a'), (2, 'b'), (1, 'b'), (0, 'a')];
+    assert_eq!(b.try_sort_by(|x, y| Ok::<_, ()>(x.0.cmp(&y.0))), Ok(()));
+    assert_eq!(b, arr![(0, 'a'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+
+    let mut empty = GenericArray::<i32, U0>::default();
+    assert_eq!(empty.try_sort_by(|_, _| Err(())), Ok(()));
+}
+
+#[test]
+fn test_try_sort_by_error() {
+    let original = GenericArray::<u32, U64>::generate(|i| (i as u32 * 7919) % 61);
+
+    for fail_after in [0, 1, 10, 100] {
+        let mut a = original;
+        let mut calls = 0;
+
+        let res = a.try_sort_by(|x, y| {
+            calls += 1;
+            match calls > fail_after {
+                true => Err(calls),
+                false => Ok(x.cmp(y)),
+            }
+        });
+
+        assert_eq!(res, Err(fail_after + 1));
+
+        let (mut sorted, mut expected) = (a, original);
+        sorted.sort();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+}
+
+#[test]
+fn test_is_sorted() {
+    assert!(arr![1, 2, 3, 4].is_sorted());
+    assert!(!arr![4, 3, 2, 1].is_sorted());
+    assert!(arr![5, 5, 5].is_sorted());
+    assert!(arr![7].is_sorted());
+    assert!(arr![0u8; U0].is_sorted());
+
+    assert!(arr![-1.0, 0.0, 1.0].is_sorted());
+    assert!(!arr![-1.0, f64::NAN, 1.0].is_sorted());
+    assert!(!arr![f64::NAN, f64::NAN].is_sorted());
+    assert!(arr![f64::NAN].is_sorted());
+
+    assert!(arr![4, 3, 2, 1].is_sorted_by_key(|x: &i32| -x));
+    assert!(!arr![1, 2, 3, 4].is_sorted_by_key(|x: &i32| -x));
+    assert!(!arr![1.0, 2.0].is_sorted_by_key(|x: &f32| x * f32::NAN));
+
+    for a in [arr![1, 2, 3], arr![3, 1, 2], arr![2, 2, 1]] {
+        assert_eq!(a.is_sorted(), a.as_slice().is_sorted());
+    }
+}
+
+#[test]
+fn test_partition_in_place() {
+    let mut a = GenericArray::<u32, U64>::generate(|i| (i as u32 * 37) % 64);
+    let mut calls = 0;
+
+    let i = a.partition_in_place(|&x| {
+        calls += 1;
+        x < 20
+    });
+
+    assert_eq!(i, 20);
+    assert_eq!(calls, 64);
+    assert!(a[..i].iter().all(|&x| x < 20));
+    assert!(a[i..].iter().all(|&x| x >= 20));
+
+    let mut sorted = a;
+    sorted.sort();
+    assert!(sorted.iter().copied().eq(0..64));
+
+    // move-only elements
+    let mut b = arr![
+        String::from("a"),
+        String::from("bb"),
+        String::from("c"),
+        String::from("dd")
+    ];
+    assert_eq!(b.partition_in_place(|s| s.len() == 2), 2);
+    assert!(b[..2].iter().all(|s| s.len() == 2));
+
+    assert_eq!(arr![1, 2, 3].partition_in_place(|_| true), 3);
+    assert_eq!(arr![1, 2, 3].partition_in_place(|_| false), 0);
+    assert_eq!(arr![0u8; U0].partition_in_place(|_| true), 0);
+}
+
+#[test]
+fn test_select_nth() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0x5e1ec7);
+
+    for k in 0..64 {
+        // small value range to exercise duplicates
+        let mut a = GenericArray::<u8, U64>::generate(|_| rng.random_range(0..16));
+
+        let mut sorted = a;
+        sorted.sort();
+
+        let nth = *a.select_nth(k);
+        assert_eq!(nth, sorted[k]);
+        assert_eq!(a[k], nth);
+        assert!(a[..k].iter().all(|&x| x <= nth));
+        assert!(a[k + 1..].iter().all(|&x| x >= nth));
+    }
+
+    let mut b = arr![3];
+    *b.select_nth(0) += 1;
+    assert_eq!(b, arr![4]);
+
+    let res = catch_unwind(|| *arr![1, 2, 3].select_nth(3));
+    assert!(res.is_err());
+
+    // the slice method is not shadowed
+    let mut c = arr![5, 1, 4, 2, 3];
+    let (lo, mid, hi) = c.select_nth_unstable(2);
+    assert_eq!((lo.len(), *mid, hi.len()), (2, 3, 2));
+}